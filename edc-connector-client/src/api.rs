@@ -1,16 +1,19 @@
 mod assets;
+mod bulk;
 mod catalog;
 mod contract_agreement;
 mod contract_definitions;
 mod contract_negotiations;
 mod dataplanes;
 mod edrs;
+mod pagination;
 mod participants;
 mod policies;
 mod secrets;
 mod transfer_process;
 
 pub use assets::AssetApi;
+pub use bulk::BulkReport;
 pub use catalog::CatalogApi;
 pub use contract_agreement::ContractAgreementApi;
 pub use contract_definitions::ContractDefinitionApi;
@@ -1,10 +1,17 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::{
+        bulk::{self, BulkReport},
+        pagination::paginate,
+    },
     client::EdcConnectorClientInternal,
     types::{
         asset::{Asset, NewAsset},
         context::WithContext,
-        query::Query,
-        response::IdResponse,
+        query::{Pagination, Query},
+        response::{IdResponse, UpsertOutcome},
     },
     EdcResult,
 };
@@ -16,6 +23,11 @@ impl<'a> AssetApi<'a> {
         AssetApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "create")
+    )]
     pub async fn create(&self, asset: &NewAsset) -> EdcResult<IdResponse<String>> {
         let url = self.0.path_for(&["assets"]);
         self.0
@@ -24,6 +36,11 @@ impl<'a> AssetApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<Asset> {
         let url = self.0.path_for(&["assets", id]);
         self.0
@@ -32,21 +49,93 @@ impl<'a> AssetApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "update")
+    )]
     pub async fn update(&self, asset: &Asset) -> EdcResult<()> {
         let url = self.0.path_for(&["assets"]);
         self.0.put(url, &self.0.context_for(asset)).await
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "upsert", id = %asset.id())
+    )]
+    pub async fn upsert(&self, asset: &Asset) -> EdcResult<UpsertOutcome> {
+        let url = self.0.path_for(&["assets"]);
+        let created = self
+            .0
+            .post::<_, WithContext<IdResponse<String>>>(url, &self.0.context_for(asset))
+            .await;
+
+        match created {
+            Ok(_) => Ok(UpsertOutcome::Created),
+            Err(err) if err.is_conflict() => {
+                self.update(asset).await.map(|_| UpsertOutcome::Updated)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument(level = "debug", skip_all, fields(api = "assets", operation = "query"))]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<Asset>> {
         let url = self.0.path_for(&["assets", "request"]);
         self.0
-            .post::<_, Vec<WithContext<Asset>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<Asset>>>(url, &self.0.context_for(&query))
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = EdcResult<Asset>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<Asset>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            AssetApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<Asset>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "assets", operation = "delete", id = %id)
+    )]
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["assets", id]);
         self.0.del(url).await
     }
+
+    pub async fn create_many(
+        &self,
+        items: impl IntoIterator<Item = NewAsset>,
+        concurrency: usize,
+    ) -> BulkReport<NewAsset, IdResponse<String>> {
+        bulk::run(items, concurrency, async |asset| self.create(asset).await).await
+    }
+
+    pub async fn delete_many(
+        &self,
+        ids: impl IntoIterator<Item = String>,
+        concurrency: usize,
+    ) -> BulkReport<String, ()> {
+        bulk::run(ids, concurrency, async |id| self.delete(id).await).await
+    }
 }
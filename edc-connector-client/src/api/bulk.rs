@@ -0,0 +1,61 @@
+use futures::{stream, StreamExt};
+
+use crate::{EdcResult, Error};
+
+/// The outcome of a bulk operation, pairing every input with its own result.
+#[derive(Debug)]
+pub struct BulkReport<I, T> {
+    results: Vec<(I, EdcResult<T>)>,
+}
+
+impl<I, T> BulkReport<I, T> {
+    pub fn results(&self) -> &[(I, EdcResult<T>)] {
+        &self.results
+    }
+
+    pub fn into_results(self) -> Vec<(I, EdcResult<T>)> {
+        self.results
+    }
+
+    pub fn succeeded(&self) -> impl Iterator<Item = (&I, &T)> {
+        self.results
+            .iter()
+            .filter_map(|(input, result)| result.as_ref().ok().map(|value| (input, value)))
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = (&I, &Error)> {
+        self.results
+            .iter()
+            .filter_map(|(input, result)| result.as_ref().err().map(|err| (input, err)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+}
+
+pub(crate) async fn run<I, T>(
+    items: impl IntoIterator<Item = I>,
+    concurrency: usize,
+    op: impl AsyncFn(&I) -> EdcResult<T>,
+) -> BulkReport<I, T> {
+    let op = &op;
+    let results = stream::iter(items)
+        .map(|item| async move {
+            let result = op(&item).await;
+            (item, result)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    BulkReport { results }
+}
@@ -1,3 +1,5 @@
+use tracing::instrument;
+
 use crate::{
     client::EdcConnectorClientInternal,
     types::{
@@ -14,19 +16,29 @@ impl<'a> CatalogApi<'a> {
         CatalogApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "catalog", operation = "request")
+    )]
     pub async fn request(&self, request: &CatalogRequest) -> EdcResult<Catalog> {
         let url = self.0.path_for(&["catalog", "request"]);
 
         self.0
-            .post::<_, WithContext<Catalog>>(url, &self.0.context_for(request))
+            .post_idempotent::<_, WithContext<Catalog>>(url, &self.0.context_for(request))
             .await
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "catalog", operation = "dataset")
+    )]
     pub async fn dataset(&self, request: &DatasetRequest) -> EdcResult<Dataset> {
         let url = self.0.path_for(&["catalog", "dataset", "request"]);
         self.0
-            .post::<_, WithContext<Dataset>>(url, &self.0.context_for(request))
+            .post_idempotent::<_, WithContext<Dataset>>(url, &self.0.context_for(request))
             .await
             .map(|ctx| ctx.inner)
     }
@@ -1,6 +1,14 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
-    types::{context::WithContext, contract_agreement::ContractAgreement, query::Query},
+    types::{
+        context::WithContext,
+        contract_agreement::ContractAgreement,
+        query::{Pagination, Query},
+    },
     EdcResult,
 };
 
@@ -11,6 +19,11 @@ impl<'a> ContractAgreementApi<'a> {
         ContractAgreementApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_agreements", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<ContractAgreement> {
         let url = self.0.path_for(&["contractagreements", id]);
         self.0
@@ -19,11 +32,46 @@ impl<'a> ContractAgreementApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_agreements", operation = "query")
+    )]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
         let url = self.0.path_for(&["contractagreements", "request"]);
         self.0
-            .post::<_, Vec<WithContext<ContractAgreement>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<ContractAgreement>>>(
+                url,
+                &self.0.context_for(&query),
+            )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
+
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractAgreement>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<ContractAgreement>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            ContractAgreementApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_agreements", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
+        self.query_stream(query).try_collect().await
+    }
 }
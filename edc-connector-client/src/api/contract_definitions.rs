@@ -1,10 +1,17 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::{
+        bulk::{self, BulkReport},
+        pagination::paginate,
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::WithContext,
         contract_definition::{ContractDefinition, NewContractDefinition},
-        query::Query,
-        response::IdResponse,
+        query::{Pagination, Query},
+        response::{IdResponse, UpsertOutcome},
     },
     EdcResult,
 };
@@ -16,6 +23,11 @@ impl<'a> ContractDefinitionApi<'a> {
         ContractDefinitionApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "create")
+    )]
     pub async fn create(
         &self,
         contract_definition: &NewContractDefinition,
@@ -30,6 +42,11 @@ impl<'a> ContractDefinitionApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<ContractDefinition> {
         let url = self.0.path_for(&["contractdefinitions", id]);
         self.0
@@ -38,6 +55,11 @@ impl<'a> ContractDefinitionApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "update")
+    )]
     pub async fn update(&self, contract_definition: &ContractDefinition) -> EdcResult<()> {
         let url = self.0.path_for(&["contractdefinitions"]);
         self.0
@@ -45,16 +67,103 @@ impl<'a> ContractDefinitionApi<'a> {
             .await
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "upsert", id = %contract_definition.id())
+    )]
+    pub async fn upsert(
+        &self,
+        contract_definition: &ContractDefinition,
+    ) -> EdcResult<UpsertOutcome> {
+        let url = self.0.path_for(&["contractdefinitions"]);
+        let created = self
+            .0
+            .post::<_, WithContext<IdResponse<String>>>(
+                url,
+                &self.0.context_for(contract_definition),
+            )
+            .await;
+
+        match created {
+            Ok(_) => Ok(UpsertOutcome::Created),
+            Err(err) if err.is_conflict() => self
+                .update(contract_definition)
+                .await
+                .map(|_| UpsertOutcome::Updated),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "query")
+    )]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractDefinition>> {
         let url = self.0.path_for(&["contractdefinitions", "request"]);
         self.0
-            .post::<_, Vec<WithContext<ContractDefinition>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<ContractDefinition>>>(
+                url,
+                &self.0.context_for(&query),
+            )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractDefinition>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<ContractDefinition>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            ContractDefinitionApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractDefinition>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_definitions", operation = "delete", id = %id)
+    )]
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["contractdefinitions", id]);
         self.0.del(url).await
     }
+
+    pub async fn create_many(
+        &self,
+        items: impl IntoIterator<Item = NewContractDefinition>,
+        concurrency: usize,
+    ) -> BulkReport<NewContractDefinition, IdResponse<String>> {
+        bulk::run(items, concurrency, async |contract_definition| {
+            self.create(contract_definition).await
+        })
+        .await
+    }
+
+    pub async fn delete_many(
+        &self,
+        ids: impl IntoIterator<Item = String>,
+        concurrency: usize,
+    ) -> BulkReport<String, ()> {
+        bulk::run(ids, concurrency, async |id| self.delete(id).await).await
+    }
 }
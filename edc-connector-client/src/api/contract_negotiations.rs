@@ -1,4 +1,10 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
+#[cfg(feature = "callbacks")]
+use crate::callbacks::{CallbackWait, Event};
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
     types::{
         context::WithContext,
@@ -6,9 +12,10 @@ use crate::{
             ContractNegotiation, ContractNegotiationState, ContractRequest, NegotiationState,
             TerminateNegotiation,
         },
-        query::Query,
+        query::{Pagination, Query},
         response::IdResponse,
     },
+    wait::{self, WaitOptions},
     EdcResult,
 };
 
@@ -19,6 +26,11 @@ impl<'a> ContractNegotiationApi<'a> {
         ContractNegotiationApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "initiate")
+    )]
     pub async fn initiate(
         &self,
         contract_request: &ContractRequest,
@@ -33,6 +45,11 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<ContractNegotiation> {
         let url = self.0.path_for(&["contractnegotiations", id]);
         self.0
@@ -41,6 +58,11 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "get_state", id = %id)
+    )]
     pub async fn get_state(&self, id: &str) -> EdcResult<ContractNegotiationState> {
         let url = self.0.path_for(&["contractnegotiations", id]);
         self.0
@@ -49,6 +71,11 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|ctx| ctx.inner.state().clone())
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "terminate", id = %id)
+    )]
     pub async fn terminate(&self, id: &str, reason: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["contractnegotiations", id, "terminate"]);
         let request = TerminateNegotiation {
@@ -61,11 +88,107 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|_| ())
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "query")
+    )]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
         let url = self.0.path_for(&["contractnegotiations", "request"]);
         self.0
-            .post::<_, Vec<WithContext<ContractNegotiation>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<ContractNegotiation>>>(
+                url,
+                &self.0.context_for(&query),
+            )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
+
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractNegotiation>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<ContractNegotiation>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            ContractNegotiationApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "wait_for_state", id = %id, target = target.as_str())
+    )]
+    pub async fn wait_for_state(
+        &self,
+        id: &str,
+        target: ContractNegotiationState,
+        options: WaitOptions,
+    ) -> EdcResult<ContractNegotiation> {
+        wait::wait_for_state(
+            id,
+            &target,
+            &options,
+            || self.get(id),
+            |entity| entity.state(),
+        )
+        .await
+    }
+
+    /// Initiates the negotiation with a callback registered for its events, then waits for
+    /// `target` as [`wait_for_state`](Self::wait_for_state) does, completing on the callback
+    /// event when it arrives.
+    #[cfg(feature = "callbacks")]
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "contract_negotiations", operation = "initiate_and_wait", target = target.as_str())
+    )]
+    pub async fn initiate_and_wait(
+        &self,
+        mut contract_request: ContractRequest,
+        target: ContractNegotiationState,
+        options: WaitOptions,
+        callbacks: &CallbackWait,
+    ) -> EdcResult<ContractNegotiation> {
+        let events = callbacks.subscribe();
+        contract_request.add_callback_address(callbacks.callback_address("contract.negotiation"));
+
+        let id = self.initiate(&contract_request).await?.id().to_string();
+
+        events
+            .wait_for_state(
+                &id,
+                &target,
+                &options,
+                |event| match event {
+                    Event::ContractNegotiation(kind, event)
+                        if event.contract_negotiation_id() == id =>
+                    {
+                        Some(kind.state())
+                    }
+                    _ => None,
+                },
+                || self.get(&id),
+                |entity| entity.state(),
+            )
+            .await
+    }
 }
@@ -1,3 +1,5 @@
+use tracing::instrument;
+
 use crate::{
     client::EdcConnectorClientInternal,
     types::{context::WithContext, dataplane::DataPlaneInstance},
@@ -11,6 +13,11 @@ impl<'a> DataPlaneApi<'a> {
         DataPlaneApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "data_planes", operation = "list")
+    )]
     pub async fn list(&self) -> EdcResult<Vec<DataPlaneInstance>> {
         let url = self.0.path_for(&["dataplanes"]);
         self.0
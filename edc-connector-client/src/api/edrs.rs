@@ -1,10 +1,15 @@
-use reqwest::StatusCode;
+use futures::{Stream, TryStreamExt};
+use reqwest::{Method, StatusCode};
+use tracing::instrument;
 
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
     types::{
-        context::WithContext, data_address::DataAddress, edr::EndpointDataReferenceEntry,
-        query::Query,
+        context::WithContext,
+        data_address::DataAddress,
+        edr::EndpointDataReferenceEntry,
+        query::{Pagination, Query},
     },
     EdcResult,
 };
@@ -16,36 +21,97 @@ impl<'a> EdrApi<'a> {
         EdrApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "get_entry", id = %id)
+    )]
     pub async fn get_entry(&self, id: &str) -> EdcResult<EndpointDataReferenceEntry> {
         let query = Query::builder()
             .filter("transferProcessId", "=", id)
             .build();
 
         self.query(query).await.and_then(|edrs| {
-            edrs.into_iter().next().ok_or_else(|| {
-                crate::Error::ManagementApi(crate::ManagementApiError {
-                    status_code: StatusCode::NOT_FOUND,
-                    error_detail: crate::ManagementApiErrorDetailKind::Raw(format!(
-                        "EDR entry with id {} not found",
-                        id
-                    )),
+            edrs.into_iter()
+                .next()
+                .ok_or_else(|| self.not_found(format!("EDR entry with id {} not found", id)))
+        })
+    }
+
+    /// Returns the most recent EDR entry negotiated for `asset_id` with `provider_id`.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "get_latest_entry", asset_id = %asset_id, provider_id = %provider_id)
+    )]
+    pub async fn get_latest_entry(
+        &self,
+        asset_id: &str,
+        provider_id: &str,
+    ) -> EdcResult<EndpointDataReferenceEntry> {
+        let query = Query::builder()
+            .filter("assetId", "=", asset_id)
+            .filter("providerId", "=", provider_id)
+            .build();
+
+        self.query_all(query).await.and_then(|edrs| {
+            edrs.into_iter()
+                .max_by_key(|edr| edr.created_at())
+                .ok_or_else(|| {
+                    self.not_found(format!(
+                        "EDR entry for asset {} from provider {} not found",
+                        asset_id, provider_id
+                    ))
                 })
-            })
         })
     }
 
     pub async fn get_data_address(&self, id: &str) -> EdcResult<DataAddress> {
-        let url = self.0.path_for(&["edrs", id, "dataaddress"]);
+        self.get_data_address_with_opts(id, false).await
+    }
+
+    /// Fetches the EDR data address, letting the connector refresh an expired token first when
+    /// `auto_refresh` is set.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "get_data_address", id = %id, auto_refresh)
+    )]
+    pub async fn get_data_address_with_opts(
+        &self,
+        id: &str,
+        auto_refresh: bool,
+    ) -> EdcResult<DataAddress> {
+        let mut url = self.0.path_for(&["edrs", id, "dataaddress"]);
+        if auto_refresh {
+            url.push_str("?auto_refresh=true");
+        }
+
         self.0
             .get::<WithContext<DataAddress>>(url)
             .await
             .map(|ctx| ctx.inner)
     }
 
+    /// Refreshes the EDR token and returns the updated data address.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "refresh", id = %id)
+    )]
+    pub async fn refresh(&self, id: &str) -> EdcResult<DataAddress> {
+        let url = self.0.path_for(&["edrs", id, "refresh"]);
+        self.0
+            .post_without_body::<WithContext<DataAddress>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    #[instrument(level = "debug", skip_all, fields(api = "edrs", operation = "query"))]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
         let url = self.0.path_for(&["edrs", "request"]);
         self.0
-            .post::<_, Vec<WithContext<EndpointDataReferenceEntry>>>(
+            .post_idempotent::<_, Vec<WithContext<EndpointDataReferenceEntry>>>(
                 url,
                 &self.0.context_for(&query),
             )
@@ -53,8 +119,49 @@ impl<'a> EdrApi<'a> {
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<EndpointDataReferenceEntry>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<EndpointDataReferenceEntry>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            EdrApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "edrs", operation = "delete", id = %id)
+    )]
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["edrs", id]);
         self.0.del(url).await
     }
+
+    pub(crate) fn not_found(&self, message: String) -> crate::Error {
+        crate::Error::ManagementApi(crate::ManagementApiError {
+            status_code: StatusCode::NOT_FOUND,
+            error_detail: crate::ManagementApiErrorDetailKind::Raw(message),
+            method: Method::POST,
+            url: self.0.path_for(&["edrs", "request"]),
+        })
+    }
 }
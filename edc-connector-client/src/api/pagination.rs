@@ -0,0 +1,65 @@
+use futures::{stream, Stream, TryStreamExt};
+use std::future::Future;
+
+use crate::{
+    types::query::{Pagination, Query},
+    EdcResult,
+};
+
+struct PageState {
+    offset: u32,
+    remaining: Option<usize>,
+    done: bool,
+}
+
+/// Walks the result set of a query endpoint page by page, advancing `offset` by the page
+/// size until a short page is returned or `max_items` have been yielded.
+pub(crate) fn paginate<'a, T, F, Fut>(
+    query: Query,
+    pagination: Pagination,
+    fetch: F,
+) -> impl Stream<Item = EdcResult<T>> + 'a
+where
+    T: 'a,
+    F: Fn(Query) -> Fut + 'a,
+    Fut: Future<Output = EdcResult<Vec<T>>> + 'a,
+{
+    let page_size = pagination.page_size().unwrap_or(query.limit()).max(1);
+    let state = PageState {
+        offset: query.offset(),
+        remaining: pagination.max_items(),
+        done: false,
+    };
+
+    stream::try_unfold(state, move |mut state| {
+        let next = if state.done || state.remaining == Some(0) {
+            None
+        } else {
+            let limit = state.remaining.map_or(page_size, |remaining| {
+                remaining.min(page_size as usize) as u32
+            });
+            let page_query = query.to_builder().offset(state.offset).limit(limit).build();
+            Some((limit, fetch(page_query)))
+        };
+
+        async move {
+            let Some((limit, page)) = next else {
+                return EdcResult::Ok(None);
+            };
+
+            let mut page = page.await?;
+
+            if let Some(remaining) = state.remaining {
+                page.truncate(remaining);
+                state.remaining = Some(remaining - page.len());
+            }
+
+            state.done = (page.len() as u32) < limit;
+            state.offset += limit;
+
+            Ok(Some((page, state)))
+        }
+    })
+    .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+}
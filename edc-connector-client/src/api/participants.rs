@@ -1,8 +1,13 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::pagination::paginate,
     client::{ApiTarget, EdcConnectorClientInternal},
     types::{
         context::WithContext,
-        participants::{NewParticipantContext, ParticipantContextConfig},
+        participants::{NewParticipantContext, ParticipantContext, ParticipantContextConfig},
+        query::{Pagination, Query},
         response::IdResponse,
     },
     EdcResult,
@@ -15,6 +20,11 @@ impl<'a> ParticipantContextApi<'a> {
         ParticipantContextApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "create")
+    )]
     pub async fn create(&self, ctx: &NewParticipantContext) -> EdcResult<IdResponse<String>> {
         let url = self.0.path_for_target(ApiTarget::Admin, &["participants"]);
         self.0
@@ -22,6 +32,118 @@ impl<'a> ParticipantContextApi<'a> {
             .await
             .map(|ctx| ctx.inner)
     }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "get", id = %id)
+    )]
+    pub async fn get(&self, id: &str) -> EdcResult<ParticipantContext> {
+        let url = self
+            .0
+            .path_for_target(ApiTarget::Admin, &["participants", id]);
+        self.0
+            .get::<WithContext<ParticipantContext>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "update", id = %ctx.id())
+    )]
+    pub async fn update(&self, ctx: &ParticipantContext) -> EdcResult<()> {
+        let url = self
+            .0
+            .path_for_target(ApiTarget::Admin, &["participants", ctx.id()]);
+        self.0.put(url, &self.0.context_for(ctx)).await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "query")
+    )]
+    pub async fn query(&self, query: Query) -> EdcResult<Vec<ParticipantContext>> {
+        let url = self
+            .0
+            .path_for_target(ApiTarget::Admin, &["participants", "request"]);
+        self.0
+            .post_idempotent::<_, Vec<WithContext<ParticipantContext>>>(
+                url,
+                &self.0.context_for(&query),
+            )
+            .await
+            .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
+    }
+
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ParticipantContext>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<ParticipantContext>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            ParticipantContextApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ParticipantContext>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "delete", id = %id)
+    )]
+    pub async fn delete(&self, id: &str) -> EdcResult<()> {
+        let url = self
+            .0
+            .path_for_target(ApiTarget::Admin, &["participants", id]);
+        self.0.del(url).await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "activate", id = %id)
+    )]
+    pub async fn activate(&self, id: &str) -> EdcResult<()> {
+        self.set_active(id, true).await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participants", operation = "deactivate", id = %id)
+    )]
+    pub async fn deactivate(&self, id: &str) -> EdcResult<()> {
+        self.set_active(id, false).await
+    }
+
+    async fn set_active(&self, id: &str, active: bool) -> EdcResult<()> {
+        let url = format!(
+            "{}?isActive={}",
+            self.0
+                .path_for_target(ApiTarget::Admin, &["participants", id, "state"]),
+            active
+        );
+        self.0.post_without_body_no_response(url).await
+    }
 }
 
 pub struct ParticipantContextConfigApi<'a>(&'a EdcConnectorClientInternal);
@@ -31,6 +153,27 @@ impl<'a> ParticipantContextConfigApi<'a> {
         ParticipantContextConfigApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participant_configs", operation = "get", participant_context_id = %participant_context_id)
+    )]
+    pub async fn get(&self, participant_context_id: &str) -> EdcResult<ParticipantContextConfig> {
+        let url = self.0.path_for_target(
+            ApiTarget::Admin,
+            &["participants", participant_context_id, "config"],
+        );
+        self.0
+            .get::<WithContext<ParticipantContextConfig>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "participant_configs", operation = "save", participant_context_id = %participant_context_id)
+    )]
     pub async fn save(
         &self,
         participant_context_id: &str,
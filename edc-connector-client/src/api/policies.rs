@@ -1,10 +1,17 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::{
+        bulk::{self, BulkReport},
+        pagination::paginate,
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::WithContext,
         policy::{NewPolicyDefinition, PolicyDefinition},
-        query::Query,
-        response::IdResponse,
+        query::{Pagination, Query},
+        response::{IdResponse, UpsertOutcome},
     },
     EdcResult,
 };
@@ -16,6 +23,11 @@ impl<'a> PolicyApi<'a> {
         PolicyApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "create")
+    )]
     pub async fn create(
         &self,
         policy_definition: &NewPolicyDefinition,
@@ -30,6 +42,11 @@ impl<'a> PolicyApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<PolicyDefinition> {
         let url = self.0.path_for(&["policydefinitions", id]);
         self.0
@@ -38,6 +55,11 @@ impl<'a> PolicyApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "update")
+    )]
     pub async fn update(&self, policy_definition: &PolicyDefinition) -> EdcResult<()> {
         let url = self
             .0
@@ -47,16 +69,100 @@ impl<'a> PolicyApi<'a> {
             .await
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "upsert", id = %policy_definition.id())
+    )]
+    pub async fn upsert(&self, policy_definition: &PolicyDefinition) -> EdcResult<UpsertOutcome> {
+        let url = self.0.path_for(&["policydefinitions"]);
+        let created = self
+            .0
+            .post::<_, WithContext<IdResponse<String>>>(
+                url,
+                &self.0.context_for_with_opts(policy_definition, true),
+            )
+            .await;
+
+        match created {
+            Ok(_) => Ok(UpsertOutcome::Created),
+            Err(err) if err.is_conflict() => self
+                .update(policy_definition)
+                .await
+                .map(|_| UpsertOutcome::Updated),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "query")
+    )]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
         let url = self.0.path_for(&["policydefinitions", "request"]);
         self.0
-            .post::<_, Vec<WithContext<PolicyDefinition>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<PolicyDefinition>>>(
+                url,
+                &self.0.context_for(&query),
+            )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<PolicyDefinition>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<PolicyDefinition>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            PolicyApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "policies", operation = "delete", id = %id)
+    )]
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["policydefinitions", id]);
         self.0.del(url).await
     }
+
+    pub async fn create_many(
+        &self,
+        items: impl IntoIterator<Item = NewPolicyDefinition>,
+        concurrency: usize,
+    ) -> BulkReport<NewPolicyDefinition, IdResponse<String>> {
+        bulk::run(items, concurrency, async |policy_definition| {
+            self.create(policy_definition).await
+        })
+        .await
+    }
+
+    pub async fn delete_many(
+        &self,
+        ids: impl IntoIterator<Item = String>,
+        concurrency: usize,
+    ) -> BulkReport<String, ()> {
+        bulk::run(ids, concurrency, async |id| self.delete(id).await).await
+    }
 }
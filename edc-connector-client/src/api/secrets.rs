@@ -1,9 +1,14 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
     types::{
         context::WithContext,
-        response::IdResponse,
-        secret::{NewSecret, Secret},
+        query::{Pagination, Query},
+        response::{IdResponse, UpsertOutcome},
+        secret::{NewSecret, Secret, SecretId},
     },
     EdcResult,
 };
@@ -15,6 +20,11 @@ impl<'a> SecretsApi<'a> {
         SecretsApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "create")
+    )]
     pub async fn create(&self, secret: &NewSecret) -> EdcResult<IdResponse<String>> {
         let url = self.0.path_for(&["secrets"]);
         self.0
@@ -23,6 +33,11 @@ impl<'a> SecretsApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<Secret> {
         let url = self.0.path_for(&["secrets", id]);
         self.0
@@ -31,13 +46,113 @@ impl<'a> SecretsApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "update")
+    )]
     pub async fn update(&self, secret: &Secret) -> EdcResult<()> {
         let url = self.0.path_for(&["secrets"]);
         self.0.put(url, &self.0.context_for(secret)).await
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "upsert", id = %secret.id())
+    )]
+    pub async fn upsert(&self, secret: &Secret) -> EdcResult<UpsertOutcome> {
+        let url = self.0.path_for(&["secrets"]);
+        let created = self
+            .0
+            .post::<_, WithContext<IdResponse<String>>>(url, &self.0.context_for(secret))
+            .await;
+
+        match created {
+            Ok(_) => Ok(UpsertOutcome::Created),
+            Err(err) if err.is_conflict() => {
+                self.update(secret).await.map(|_| UpsertOutcome::Updated)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "delete", id = %id)
+    )]
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["secrets", id]);
         self.0.del(url).await
     }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "query")
+    )]
+    pub async fn query(&self, query: Query) -> EdcResult<Vec<Secret>> {
+        let url = self.0.path_for(&["secrets", "request"]);
+        self.0
+            .post_idempotent::<_, Vec<WithContext<Secret>>>(url, &self.0.context_for(&query))
+            .await
+            .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
+    }
+
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = EdcResult<Secret>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<Secret>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            SecretsApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<Secret>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    /// Like [`SecretsApi::query`], but returns only the ids of the matching secrets.
+    ///
+    /// The connector still sends the secret values: they are downloaded and buffered with the
+    /// rest of the response body, and only skipped when decoding the ids.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "secrets", operation = "query_ids")
+    )]
+    pub async fn query_ids(&self, query: Query) -> EdcResult<Vec<String>> {
+        let url = self.0.path_for(&["secrets", "request"]);
+        self.0
+            .post_idempotent::<_, Vec<SecretId>>(url, &self.0.context_for(&query))
+            .await
+            .map(|results| results.into_iter().map(|secret| secret.id).collect())
+    }
+
+    pub fn query_ids_stream(&self, query: Query) -> impl Stream<Item = EdcResult<String>> + 'a {
+        self.query_ids_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_ids_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<String>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            SecretsApi(client).query_ids(query).await
+        })
+    }
 }
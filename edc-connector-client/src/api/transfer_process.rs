@@ -1,14 +1,21 @@
+use futures::{Stream, TryStreamExt};
+use tracing::instrument;
+
+#[cfg(feature = "callbacks")]
+use crate::callbacks::{CallbackWait, Event};
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
     types::{
         context::WithContext,
-        query::Query,
+        query::{Pagination, Query},
         response::IdResponse,
         transfer_process::{
             SuspendTransfer, TerminateTransfer, TransferProcess, TransferProcessState,
             TransferRequest, TransferState,
         },
     },
+    wait::{self, WaitOptions},
     EdcResult,
 };
 
@@ -19,6 +26,11 @@ impl<'a> TransferProcessApi<'a> {
         TransferProcessApi(client)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "initiate")
+    )]
     pub async fn initiate(
         &self,
         transfer_request: &TransferRequest,
@@ -30,6 +42,11 @@ impl<'a> TransferProcessApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "get", id = %id)
+    )]
     pub async fn get(&self, id: &str) -> EdcResult<TransferProcess> {
         let url = self.0.path_for(&["transferprocesses", id]);
         self.0
@@ -38,6 +55,11 @@ impl<'a> TransferProcessApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "get_state", id = %id)
+    )]
     pub async fn get_state(&self, id: &str) -> EdcResult<TransferProcessState> {
         let url = self.0.path_for(&["transferprocesses", id]);
         self.0
@@ -46,15 +68,55 @@ impl<'a> TransferProcessApi<'a> {
             .map(|ctx| ctx.inner.state().clone())
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "query")
+    )]
     pub async fn query(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
         let url = self.0.path_for(&["transferprocesses", "request"]);
 
         self.0
-            .post::<_, Vec<WithContext<TransferProcess>>>(url, &self.0.context_for(&query))
+            .post_idempotent::<_, Vec<WithContext<TransferProcess>>>(
+                url,
+                &self.0.context_for(&query),
+            )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<TransferProcess>> + 'a {
+        self.query_stream_with(query, Pagination::default())
+    }
+
+    pub fn query_stream_with(
+        &self,
+        query: Query,
+        pagination: Pagination,
+    ) -> impl Stream<Item = EdcResult<TransferProcess>> + 'a {
+        let client = self.0;
+        paginate(query, pagination, move |query| async move {
+            TransferProcessApi(client).query(query).await
+        })
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "query_all")
+    )]
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "terminate", id = %id)
+    )]
     pub async fn terminate(&self, id: &str, reason: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["transferprocesses", id, "terminate"]);
 
@@ -68,6 +130,11 @@ impl<'a> TransferProcessApi<'a> {
             .map(|_| ())
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "suspend", id = %id)
+    )]
     pub async fn suspend(&self, id: &str, reason: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["transferprocesses", id, "suspend"]);
 
@@ -82,6 +149,11 @@ impl<'a> TransferProcessApi<'a> {
             .map(|_| ())
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "resume", id = %id)
+    )]
     pub async fn resume(&self, id: &str) -> EdcResult<()> {
         let url = self.0.path_for(&["transferprocesses", id, "resume"]);
         self.0
@@ -89,4 +161,63 @@ impl<'a> TransferProcessApi<'a> {
             .await
             .map(|_| ())
     }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "wait_for_state", id = %id, target = target.as_str())
+    )]
+    pub async fn wait_for_state(
+        &self,
+        id: &str,
+        target: TransferProcessState,
+        options: WaitOptions,
+    ) -> EdcResult<TransferProcess> {
+        wait::wait_for_state(
+            id,
+            &target,
+            &options,
+            || self.get(id),
+            |entity| entity.state(),
+        )
+        .await
+    }
+
+    /// Initiates the transfer with a callback registered for its events, then waits for
+    /// `target` as [`wait_for_state`](Self::wait_for_state) does, completing on the callback
+    /// event when it arrives.
+    #[cfg(feature = "callbacks")]
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "transfer_processes", operation = "initiate_and_wait", target = target.as_str())
+    )]
+    pub async fn initiate_and_wait(
+        &self,
+        mut transfer_request: TransferRequest,
+        target: TransferProcessState,
+        options: WaitOptions,
+        callbacks: &CallbackWait,
+    ) -> EdcResult<TransferProcess> {
+        let events = callbacks.subscribe();
+        transfer_request.add_callback_address(callbacks.callback_address("transfer.process"));
+
+        let id = self.initiate(&transfer_request).await?.id().to_string();
+
+        events
+            .wait_for_state(
+                &id,
+                &target,
+                &options,
+                |event| match event {
+                    Event::TransferProcess(kind, event) if event.transfer_process_id() == id => {
+                        Some(kind.state())
+                    }
+                    _ => None,
+                },
+                || self.get(&id),
+                |entity| entity.state(),
+            )
+            .await
+    }
 }
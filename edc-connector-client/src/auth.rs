@@ -1,14 +1,23 @@
+use std::sync::Arc;
+
+use reqwest::{header::HeaderValue, Client};
+
 use crate::EdcResult;
+pub use assertion::{AssertionAlgorithm, ClientAssertion};
 use oauth::OAuth2;
 pub use oauth::OAuth2Config;
+pub use provider::{AuthProvider, Basic, Bearer};
 
+mod assertion;
 mod oauth;
+mod provider;
 
 #[derive(Clone)]
 pub enum Auth {
     NoAuth,
     ApiToken(String),
     OAuth2(OAuth2),
+    Custom(Arc<dyn AuthProvider>),
 }
 
 impl Auth {
@@ -19,4 +28,37 @@ impl Auth {
     pub fn oauth(cfg: OAuth2Config) -> EdcResult<Auth> {
         Ok(Auth::OAuth2(OAuth2::init(cfg)?))
     }
+
+    pub fn bearer(token: impl Into<String>) -> Auth {
+        Auth::custom(Bearer::new(token))
+    }
+
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Auth {
+        Auth::custom(Basic::new(username, password))
+    }
+
+    pub fn custom(provider: impl AuthProvider + 'static) -> Auth {
+        Auth::Custom(Arc::new(provider))
+    }
+
+    pub(crate) fn bind_http_client(&self, client: &Client) {
+        if let Auth::OAuth2(oauth) = self {
+            oauth.bind_http_client(client);
+        }
+    }
+
+    /// Discards the credentials sent in `authorization` after the management API rejected
+    /// them. Returns `true` if fresh credentials can be obtained by retrying the request.
+    pub(crate) async fn invalidate(&self, authorization: Option<&HeaderValue>) -> bool {
+        match self {
+            Auth::OAuth2(oauth) => {
+                let token = authorization
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.strip_prefix("Bearer "));
+                oauth.invalidate(token).await;
+                true
+            }
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bon::Builder;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use rand::{distr::Alphanumeric, Rng};
+use serde::Serialize;
+
+use crate::{EdcResult, Error};
+
+pub(crate) const CLIENT_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AssertionAlgorithm {
+    #[default]
+    RS256,
+    ES256,
+}
+
+/// Signing material for RFC 7523 `private_key_jwt` client authentication.
+#[derive(Clone, Builder)]
+pub struct ClientAssertion {
+    #[builder(into)]
+    private_key_pem: String,
+    #[builder(default)]
+    algorithm: AssertionAlgorithm,
+    #[builder(into)]
+    key_id: Option<String>,
+    /// Defaults to the token endpoint.
+    #[builder(into)]
+    audience: Option<String>,
+    #[builder(default = Duration::from_secs(60))]
+    lifetime: Duration,
+}
+
+impl fmt::Debug for ClientAssertion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientAssertion")
+            .field("algorithm", &self.algorithm)
+            .field("key_id", &self.key_id)
+            .field("audience", &self.audience)
+            .field("lifetime", &self.lifetime)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) struct AssertionSigner {
+    key: EncodingKey,
+    header: Header,
+    client_id: String,
+    audience: Option<String>,
+    lifetime: Duration,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    jti: String,
+    iat: u64,
+    exp: u64,
+}
+
+impl AssertionSigner {
+    pub(crate) fn new(assertion: ClientAssertion, client_id: &str) -> EdcResult<Self> {
+        let pem = assertion.private_key_pem.as_bytes();
+        let (key, algorithm) = match assertion.algorithm {
+            AssertionAlgorithm::RS256 => (EncodingKey::from_rsa_pem(pem), Algorithm::RS256),
+            AssertionAlgorithm::ES256 => (EncodingKey::from_ec_pem(pem), Algorithm::ES256),
+        };
+
+        let mut header = Header::new(algorithm);
+        header.kid = assertion.key_id;
+
+        Ok(Self {
+            key: key.map_err(|e| Error::Auth(Box::new(e)))?,
+            header,
+            client_id: client_id.to_string(),
+            audience: assertion.audience,
+            lifetime: assertion.lifetime,
+        })
+    }
+
+    pub(crate) fn sign(&self, token_url: &str) -> EdcResult<String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let claims = Claims {
+            iss: &self.client_id,
+            sub: &self.client_id,
+            aud: self.audience.as_deref().unwrap_or(token_url),
+            jti: rand::rng()
+                .sample_iter(Alphanumeric)
+                .take(32)
+                .map(char::from)
+                .collect(),
+            iat: now.as_secs(),
+            exp: (now + self.lifetime).as_secs(),
+        };
+
+        jsonwebtoken::encode(&self.header, &claims, &self.key).map_err(|e| Error::Auth(Box::new(e)))
+    }
+}
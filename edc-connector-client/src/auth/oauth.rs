@@ -1,19 +1,22 @@
 use std::{
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::{Duration, Instant},
 };
 
 use bon::Builder;
 use oauth2::{
     basic::{BasicClient, BasicTokenType},
-    AccessToken, AuthUrl, ClientId, ClientSecret, EmptyExtraTokenFields, RefreshToken, Scope,
+    AccessToken, ClientId, ClientSecret, EmptyExtraTokenFields, RefreshToken, Scope,
     StandardTokenResponse, TokenResponse, TokenUrl,
 };
 use reqwest::Client;
-use tokio::sync::Mutex;
+use serde::Deserialize;
+use tokio::sync::{Mutex, OnceCell};
 
 use crate::{EdcResult, Error};
 
+use super::assertion::{AssertionSigner, ClientAssertion, CLIENT_ASSERTION_TYPE};
+
 #[derive(Clone)]
 pub struct OAuth2(Arc<OAuth2Internal>);
 
@@ -29,7 +32,7 @@ type OAuthClient = oauth2::Client<
     OAuthTokenIntrospection,
     OAuthRevocableToken,
     OAuthRevocationError,
-    oauth2::EndpointSet,
+    oauth2::EndpointNotSet,
     oauth2::EndpointNotSet,
     oauth2::EndpointNotSet,
     oauth2::EndpointNotSet,
@@ -37,10 +40,17 @@ type OAuthClient = oauth2::Client<
 >;
 
 pub struct OAuth2Internal {
-    oauth_client: OAuthClient,
+    oauth_client: OnceCell<OAuthClient>,
+    client_id: String,
+    client_secret: Option<String>,
+    issuer_url: Option<String>,
+    extra_params: Vec<(String, String)>,
     session: Mutex<Option<OAuthTokenSession>>,
-    http_client: Client,
+    http_client: OnceLock<Client>,
     scopes: Vec<String>,
+    assertion: Option<AssertionSigner>,
+    expiry_skew: Duration,
+    default_expires_in: Duration,
 }
 
 pub struct OAuthTokenSession {
@@ -70,52 +80,177 @@ impl OAuthTokenSession {
         self.refresh_token.as_ref()
     }
 
-    fn is_expired(&self) -> bool {
-        Instant::now() >= self.expires_at - (Duration::from_secs(30))
+    fn is_expired(&self, skew: Duration) -> bool {
+        self.expires_at
+            .checked_sub(skew)
+            .is_none_or(|deadline| Instant::now() >= deadline)
     }
 }
 
 #[derive(Builder)]
 pub struct OAuth2Config {
+    #[builder(field)]
+    extra_params: Vec<(String, String)>,
     #[builder(into)]
     client_id: String,
     #[builder(into)]
-    client_secret: String,
+    client_secret: Option<String>,
+    client_assertion: Option<ClientAssertion>,
     #[builder(into)]
-    token_url: String,
+    token_url: Option<String>,
+    /// Issuer used to discover the token endpoint via `/.well-known/openid-configuration` when
+    /// no `token_url` is set.
+    #[builder(into)]
+    issuer_url: Option<String>,
     #[builder(default = vec!["management-api:read".to_string(), "management-api:write".to_string()])]
     scopes: Vec<String>,
+    http_client: Option<Client>,
+    /// How long before its expiry a cached token is considered stale.
+    #[builder(default = Duration::from_secs(30))]
+    expiry_skew: Duration,
+    /// Token lifetime assumed when the token response has no `expires_in`.
+    #[builder(default = Duration::from_secs(3600))]
+    default_expires_in: Duration,
+}
+
+impl<S: o_auth2_config_builder::State> OAuth2ConfigBuilder<S> {
+    /// Adds a form parameter to every token request, e.g. `audience` or `resource`.
+    pub fn extra_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_params.push((name.into(), value.into()));
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenIdConfiguration {
+    token_endpoint: String,
 }
 
 impl OAuth2 {
     pub fn init(cfg: OAuth2Config) -> EdcResult<OAuth2> {
-        let client = BasicClient::new(ClientId::new(cfg.client_id))
-            .set_client_secret(ClientSecret::new(cfg.client_secret))
-            .set_auth_uri(
-                AuthUrl::new("http://authorize".to_string())
-                    .map_err(|e| Error::Auth(Box::new(e)))?,
-            )
-            .set_token_uri(TokenUrl::new(cfg.token_url).map_err(|e| Error::Auth(Box::new(e)))?);
-
-        Ok(OAuth2(Arc::new(OAuth2Internal {
-            oauth_client: client,
+        if cfg.client_secret.is_some() && cfg.client_assertion.is_some() {
+            return Err(Error::Auth(
+                "client_secret and client_assertion are mutually exclusive".into(),
+            ));
+        }
+
+        if cfg.token_url.is_none() && cfg.issuer_url.is_none() {
+            return Err(Error::Auth(
+                "either token_url or issuer_url is required".into(),
+            ));
+        }
+
+        let assertion = cfg
+            .client_assertion
+            .map(|assertion| AssertionSigner::new(assertion, &cfg.client_id))
+            .transpose()?;
+
+        let internal = OAuth2Internal {
+            oauth_client: OnceCell::new(),
+            client_id: cfg.client_id,
+            client_secret: cfg.client_secret,
+            issuer_url: cfg.issuer_url,
+            extra_params: cfg.extra_params,
             session: Mutex::default(),
-            http_client: Client::new(),
+            http_client: cfg.http_client.map(OnceLock::from).unwrap_or_default(),
             scopes: cfg.scopes,
-        })))
+            assertion,
+            expiry_skew: cfg.expiry_skew,
+            default_expires_in: cfg.default_expires_in,
+        };
+
+        if let Some(token_url) = cfg.token_url {
+            let client = internal.oauth_client_for(token_url)?;
+            let _ = internal.oauth_client.set(client);
+        }
+
+        Ok(OAuth2(Arc::new(internal)))
     }
 
     pub async fn token(&self) -> EdcResult<String> {
         self.0.token().await
     }
+
+    pub(crate) fn bind_http_client(&self, client: &Client) {
+        let _ = self.0.http_client.set(client.clone());
+    }
+
+    pub(crate) async fn invalidate(&self, token: Option<&str>) {
+        self.0.invalidate(token).await
+    }
 }
 
 impl OAuth2Internal {
+    fn http_client(&self) -> &Client {
+        self.http_client.get_or_init(Client::new)
+    }
+
+    fn oauth_client_for(&self, token_url: String) -> EdcResult<OAuthClient> {
+        let client = BasicClient::new(ClientId::new(self.client_id.clone()))
+            .set_token_uri(TokenUrl::new(token_url).map_err(|e| Error::Auth(Box::new(e)))?);
+
+        Ok(match &self.client_secret {
+            Some(secret) => client.set_client_secret(ClientSecret::new(secret.clone())),
+            None => client,
+        })
+    }
+
+    async fn oauth_client(&self) -> EdcResult<&OAuthClient> {
+        self.oauth_client
+            .get_or_try_init(|| async {
+                let token_url = self.discover_token_url().await?;
+                self.oauth_client_for(token_url)
+            })
+            .await
+    }
+
+    async fn discover_token_url(&self) -> EdcResult<String> {
+        let issuer = self
+            .issuer_url
+            .as_deref()
+            .ok_or_else(|| Error::Auth("issuer_url is not configured".into()))?;
+
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+
+        let configuration = self
+            .http_client()
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| Error::Auth(Box::new(e)))?
+            .json::<OpenIdConfiguration>()
+            .await
+            .map_err(|e| Error::Auth(Box::new(e)))?;
+
+        Ok(configuration.token_endpoint)
+    }
+
+    fn extra_params(&self, token_url: &TokenUrl) -> EdcResult<Vec<(String, String)>> {
+        let mut params = self.extra_params.clone();
+
+        if let Some(assertion) = &self.assertion {
+            params.push((
+                "client_assertion_type".to_string(),
+                CLIENT_ASSERTION_TYPE.to_string(),
+            ));
+            params.push((
+                "client_assertion".to_string(),
+                assertion.sign(token_url.as_str())?,
+            ));
+        }
+
+        Ok(params)
+    }
+
     pub async fn token(&self) -> EdcResult<String> {
         let mut session = self.session.lock().await;
 
         match session.as_ref() {
-            Some(t) if !t.is_expired() => Ok(t.access_token().secret().to_string()),
+            Some(t) if !t.is_expired(self.expiry_skew) => Ok(t.access_token().secret().to_string()),
             Some(t) => {
                 let new_session = self.refresh_session(t).await?;
                 let access_token = new_session.access_token().secret().to_string();
@@ -131,6 +266,19 @@ impl OAuth2Internal {
         }
     }
 
+    /// Drops the cached session if it still holds `token`, so that a concurrent caller that
+    /// already refreshed it is not invalidated again.
+    async fn invalidate(&self, token: Option<&str>) {
+        let mut session = self.session.lock().await;
+
+        if session
+            .as_ref()
+            .is_some_and(|s| token.is_none_or(|token| s.access_token().secret() == token))
+        {
+            *session = None;
+        }
+    }
+
     async fn new_session(&self) -> EdcResult<OAuthTokenSession> {
         let scopes = self
             .scopes
@@ -138,18 +286,22 @@ impl OAuth2Internal {
             .cloned()
             .map(Scope::new)
             .collect::<Vec<_>>();
-        let token_result = self
-            .oauth_client
+        let oauth_client = self.oauth_client().await?;
+        let mut request = oauth_client
             .exchange_client_credentials()
-            .add_scopes(scopes)
-            .request_async(&self.http_client)
+            .add_scopes(scopes);
+
+        for (name, value) in self.extra_params(oauth_client.token_uri())? {
+            request = request.add_extra_param(name, value);
+        }
+
+        let token_result = request
+            .request_async(self.http_client())
             .await
             .map_err(|e| Error::Auth(Box::new(e)))?;
 
-        let expires_at = Instant::now()
-            + token_result
-                .expires_in()
-                .unwrap_or(Duration::from_secs(3600));
+        let expires_at =
+            Instant::now() + token_result.expires_in().unwrap_or(self.default_expires_in);
 
         Ok(OAuthTokenSession::new(
             token_result.access_token().clone(),
@@ -160,17 +312,20 @@ impl OAuth2Internal {
 
     async fn refresh_session(&self, session: &OAuthTokenSession) -> EdcResult<OAuthTokenSession> {
         if let Some(refresh) = session.refresh_token() {
-            let token_result = self
-                .oauth_client
-                .exchange_refresh_token(refresh)
-                .request_async(&self.http_client)
+            let oauth_client = self.oauth_client().await?;
+            let mut request = oauth_client.exchange_refresh_token(refresh);
+
+            for (name, value) in self.extra_params(oauth_client.token_uri())? {
+                request = request.add_extra_param(name, value);
+            }
+
+            let token_result = request
+                .request_async(self.http_client())
                 .await
                 .map_err(|e| Error::Auth(Box::new(e)))?;
 
-            let expires_at = Instant::now()
-                + token_result
-                    .expires_in()
-                    .unwrap_or(Duration::from_secs(3600));
+            let expires_at =
+                Instant::now() + token_result.expires_in().unwrap_or(self.default_expires_in);
 
             let refresh_token = token_result
                 .refresh_token()
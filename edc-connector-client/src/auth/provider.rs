@@ -0,0 +1,78 @@
+use std::fmt;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+
+use crate::{transport::HttpRequest, EdcResult, Error};
+
+/// Decorates outgoing management API requests with authentication headers.
+///
+/// Implementations are invoked before every attempt, including retries, so they can refresh or
+/// sign credentials per request.
+pub trait AuthProvider: Send + Sync {
+    fn authenticate<'a>(&'a self, request: &'a mut HttpRequest) -> BoxFuture<'a, EdcResult<()>>;
+}
+
+/// Sends a static token in the `Authorization: Bearer` header.
+#[derive(Clone)]
+pub struct Bearer(String);
+
+impl Bearer {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl AuthProvider for Bearer {
+    fn authenticate<'a>(&'a self, request: &'a mut HttpRequest) -> BoxFuture<'a, EdcResult<()>> {
+        Box::pin(async move { set_authorization(request, format!("Bearer {}", self.0)) })
+    }
+}
+
+impl fmt::Debug for Bearer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Bearer").field(&"<redacted>").finish()
+    }
+}
+
+/// Sends HTTP Basic credentials in the `Authorization` header.
+#[derive(Clone)]
+pub struct Basic {
+    username: String,
+    password: String,
+}
+
+impl Basic {
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl AuthProvider for Basic {
+    fn authenticate<'a>(&'a self, request: &'a mut HttpRequest) -> BoxFuture<'a, EdcResult<()>> {
+        Box::pin(async move {
+            let credentials = STANDARD.encode(format!("{}:{}", self.username, self.password));
+            set_authorization(request, format!("Basic {}", credentials))
+        })
+    }
+}
+
+impl fmt::Debug for Basic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Basic")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+fn set_authorization(request: &mut HttpRequest, value: String) -> EdcResult<()> {
+    let mut value = HeaderValue::from_str(&value).map_err(|e| Error::Auth(Box::new(e)))?;
+    value.set_sensitive(true);
+    request.headers.insert(AUTHORIZATION, value);
+    Ok(())
+}
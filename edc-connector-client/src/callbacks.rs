@@ -0,0 +1,10 @@
+mod event;
+mod receiver;
+mod wait;
+
+pub use event::{
+    AssetEvent, AssetEventKind, ContractNegotiationEvent, ContractNegotiationEventKind, Event,
+    EventEnvelope, TransferProcessEvent, TransferProcessEventKind,
+};
+pub use receiver::{CallbackEvents, CallbackReceiver, EventHandler, RunningReceiver};
+pub use wait::CallbackWait;
@@ -0,0 +1,327 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::types::{
+    contract_negotiation::ContractNegotiationState, transfer_process::TransferProcessState,
+};
+
+/// A callback event as POSTed by the connector to a registered [`CallbackAddress`](crate::types::callback_address::CallbackAddress).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "RawEventEnvelope")]
+pub struct EventEnvelope {
+    id: String,
+    at: i64,
+    event: Event,
+}
+
+impl EventEnvelope {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// When the event happened, in milliseconds since the epoch.
+    pub fn at(&self) -> i64 {
+        self.at
+    }
+
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    pub fn into_event(self) -> Event {
+        self.event
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    ContractNegotiation(ContractNegotiationEventKind, ContractNegotiationEvent),
+    TransferProcess(TransferProcessEventKind, TransferProcessEvent),
+    Asset(AssetEventKind, AssetEvent),
+    /// An event type this client doesn't model, with its raw payload.
+    Other {
+        kind: String,
+        payload: Value,
+    },
+}
+
+impl Event {
+    /// The event type as sent by the connector, e.g. `TransferProcessStarted`.
+    pub fn kind(&self) -> String {
+        match self {
+            Event::ContractNegotiation(kind, _) => format!("ContractNegotiation{}", kind.as_str()),
+            Event::TransferProcess(kind, _) => format!("TransferProcess{}", kind.as_str()),
+            Event::Asset(kind, _) => format!("Asset{}", kind.as_str()),
+            Event::Other { kind, .. } => kind.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractNegotiationEventKind {
+    Initiated,
+    Requested,
+    Offered,
+    Accepted,
+    Agreed,
+    Verified,
+    Finalized,
+    Terminated,
+}
+
+impl ContractNegotiationEventKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContractNegotiationEventKind::Initiated => "Initiated",
+            ContractNegotiationEventKind::Requested => "Requested",
+            ContractNegotiationEventKind::Offered => "Offered",
+            ContractNegotiationEventKind::Accepted => "Accepted",
+            ContractNegotiationEventKind::Agreed => "Agreed",
+            ContractNegotiationEventKind::Verified => "Verified",
+            ContractNegotiationEventKind::Finalized => "Finalized",
+            ContractNegotiationEventKind::Terminated => "Terminated",
+        }
+    }
+
+    /// The state the negotiation is in when the event is emitted.
+    pub fn state(&self) -> ContractNegotiationState {
+        match self {
+            ContractNegotiationEventKind::Initiated => ContractNegotiationState::Initial,
+            ContractNegotiationEventKind::Requested => ContractNegotiationState::Requested,
+            ContractNegotiationEventKind::Offered => ContractNegotiationState::Offered,
+            ContractNegotiationEventKind::Accepted => ContractNegotiationState::Accepted,
+            ContractNegotiationEventKind::Agreed => ContractNegotiationState::Agreed,
+            ContractNegotiationEventKind::Verified => ContractNegotiationState::Verified,
+            ContractNegotiationEventKind::Finalized => ContractNegotiationState::Finalized,
+            ContractNegotiationEventKind::Terminated => ContractNegotiationState::Terminated,
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "Initiated" => Some(ContractNegotiationEventKind::Initiated),
+            "Requested" => Some(ContractNegotiationEventKind::Requested),
+            "Offered" => Some(ContractNegotiationEventKind::Offered),
+            "Accepted" => Some(ContractNegotiationEventKind::Accepted),
+            "Agreed" => Some(ContractNegotiationEventKind::Agreed),
+            "Verified" => Some(ContractNegotiationEventKind::Verified),
+            "Finalized" => Some(ContractNegotiationEventKind::Finalized),
+            "Terminated" => Some(ContractNegotiationEventKind::Terminated),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferProcessEventKind {
+    Initiated,
+    Provisioned,
+    Requested,
+    Started,
+    Suspended,
+    Completed,
+    Terminated,
+    Deprovisioned,
+}
+
+impl TransferProcessEventKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransferProcessEventKind::Initiated => "Initiated",
+            TransferProcessEventKind::Provisioned => "Provisioned",
+            TransferProcessEventKind::Requested => "Requested",
+            TransferProcessEventKind::Started => "Started",
+            TransferProcessEventKind::Suspended => "Suspended",
+            TransferProcessEventKind::Completed => "Completed",
+            TransferProcessEventKind::Terminated => "Terminated",
+            TransferProcessEventKind::Deprovisioned => "Deprovisioned",
+        }
+    }
+
+    /// The state the transfer process is in when the event is emitted.
+    pub fn state(&self) -> TransferProcessState {
+        match self {
+            TransferProcessEventKind::Initiated => TransferProcessState::Initial,
+            TransferProcessEventKind::Provisioned => TransferProcessState::Provisioned,
+            TransferProcessEventKind::Requested => TransferProcessState::Requested,
+            TransferProcessEventKind::Started => TransferProcessState::Started,
+            TransferProcessEventKind::Suspended => TransferProcessState::Suspended,
+            TransferProcessEventKind::Completed => TransferProcessState::Completed,
+            TransferProcessEventKind::Terminated => TransferProcessState::Terminated,
+            TransferProcessEventKind::Deprovisioned => TransferProcessState::Deprovisioned,
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "Initiated" => Some(TransferProcessEventKind::Initiated),
+            "Provisioned" => Some(TransferProcessEventKind::Provisioned),
+            "Requested" => Some(TransferProcessEventKind::Requested),
+            "Started" => Some(TransferProcessEventKind::Started),
+            "Suspended" => Some(TransferProcessEventKind::Suspended),
+            "Completed" => Some(TransferProcessEventKind::Completed),
+            "Terminated" => Some(TransferProcessEventKind::Terminated),
+            "Deprovisioned" => Some(TransferProcessEventKind::Deprovisioned),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl AssetEventKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AssetEventKind::Created => "Created",
+            AssetEventKind::Updated => "Updated",
+            AssetEventKind::Deleted => "Deleted",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "Created" => Some(AssetEventKind::Created),
+            "Updated" => Some(AssetEventKind::Updated),
+            "Deleted" => Some(AssetEventKind::Deleted),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractNegotiationEvent {
+    contract_negotiation_id: String,
+    counter_party_address: Option<String>,
+    counter_party_id: Option<String>,
+    protocol: Option<String>,
+    contract_agreement: Option<Value>,
+}
+
+impl ContractNegotiationEvent {
+    pub fn contract_negotiation_id(&self) -> &str {
+        &self.contract_negotiation_id
+    }
+
+    pub fn counter_party_address(&self) -> Option<&str> {
+        self.counter_party_address.as_deref()
+    }
+
+    pub fn counter_party_id(&self) -> Option<&str> {
+        self.counter_party_id.as_deref()
+    }
+
+    pub fn protocol(&self) -> Option<&str> {
+        self.protocol.as_deref()
+    }
+
+    /// The agreement carried by `Agreed`, `Verified` and `Finalized` events, as sent by the
+    /// connector.
+    pub fn contract_agreement(&self) -> Option<&Value> {
+        self.contract_agreement.as_ref()
+    }
+
+    pub fn contract_agreement_id(&self) -> Option<&str> {
+        self.contract_agreement.as_ref()?.get("id")?.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferProcessEvent {
+    transfer_process_id: String,
+    asset_id: Option<String>,
+    contract_id: Option<String>,
+    #[serde(rename = "type")]
+    process_type: Option<String>,
+    data_address: Option<Value>,
+    reason: Option<String>,
+}
+
+impl TransferProcessEvent {
+    pub fn transfer_process_id(&self) -> &str {
+        &self.transfer_process_id
+    }
+
+    pub fn asset_id(&self) -> Option<&str> {
+        self.asset_id.as_deref()
+    }
+
+    pub fn contract_id(&self) -> Option<&str> {
+        self.contract_id.as_deref()
+    }
+
+    /// `CONSUMER` or `PROVIDER`.
+    pub fn process_type(&self) -> Option<&str> {
+        self.process_type.as_deref()
+    }
+
+    /// The EDR carried by `Started` events of pull transfers.
+    pub fn data_address(&self) -> Option<&Value> {
+        self.data_address.as_ref()
+    }
+
+    /// Why the transfer was terminated.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetEvent {
+    asset_id: String,
+}
+
+impl AssetEvent {
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+}
+
+#[derive(Deserialize)]
+struct RawEventEnvelope {
+    id: String,
+    at: i64,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    payload: Value,
+}
+
+impl TryFrom<RawEventEnvelope> for EventEnvelope {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawEventEnvelope) -> Result<Self, Self::Error> {
+        let RawEventEnvelope {
+            id,
+            at,
+            kind,
+            payload,
+        } = raw;
+
+        let negotiation = kind
+            .strip_prefix("ContractNegotiation")
+            .and_then(ContractNegotiationEventKind::parse);
+        let transfer = kind
+            .strip_prefix("TransferProcess")
+            .and_then(TransferProcessEventKind::parse);
+        let asset = kind.strip_prefix("Asset").and_then(AssetEventKind::parse);
+
+        let event = match (negotiation, transfer, asset) {
+            (Some(kind), _, _) => {
+                Event::ContractNegotiation(kind, serde_json::from_value(payload)?)
+            }
+            (_, Some(kind), _) => Event::TransferProcess(kind, serde_json::from_value(payload)?),
+            (_, _, Some(kind)) => Event::Asset(kind, serde_json::from_value(payload)?),
+            _ => Event::Other { kind, payload },
+        };
+
+        Ok(EventEnvelope { id, at, event })
+    }
+}
@@ -0,0 +1,227 @@
+use std::{convert::Infallible, fmt, future::Future, io, net::SocketAddr, sync::Arc};
+
+use bon::Builder;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http_body_util::{BodyExt, Full};
+use hyper::{body::Incoming, server::conn::http1, service::service_fn, Request, Response};
+use hyper_util::rt::TokioIo;
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use tokio::{
+    net::{TcpListener, ToSocketAddrs},
+    sync::{broadcast, mpsc},
+    task::JoinHandle,
+};
+use tracing::{debug, warn};
+
+use super::EventEnvelope;
+
+pub type EventHandler = Arc<dyn Fn(EventEnvelope) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Receives the callback events POSTed by the connector and dispatches them to handlers and
+/// channels.
+///
+/// Handlers are awaited before the connector gets its response, so transactional callbacks only
+/// complete once every handler is done.
+#[derive(Clone, Builder)]
+pub struct CallbackReceiver {
+    #[builder(field)]
+    auth: Option<(String, String)>,
+    #[builder(field)]
+    handlers: Vec<EventHandler>,
+    #[builder(field)]
+    channels: Vec<mpsc::Sender<EventEnvelope>>,
+    #[builder(field)]
+    broadcasts: Vec<CallbackEvents>,
+}
+
+impl<S: callback_receiver_builder::State> CallbackReceiverBuilder<S> {
+    /// Requires the `auth_key` header to carry `secret`, the value stored in the vault under the
+    /// `authCodeId` of the [`CallbackAddressAuth`](crate::types::callback_address::CallbackAddressAuth).
+    pub fn auth(mut self, auth_key: impl Into<String>, secret: impl Into<String>) -> Self {
+        self.auth = Some((auth_key.into(), secret.into()));
+        self
+    }
+
+    pub fn handler<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(EventEnvelope) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.handlers
+            .push(Arc::new(move |envelope| Box::pin(handler(envelope))));
+        self
+    }
+
+    pub fn channel(mut self, sender: mpsc::Sender<EventEnvelope>) -> Self {
+        self.channels.push(sender);
+        self
+    }
+
+    /// Publishes every received event to `events`, e.g. to complete callback-driven waits.
+    pub fn events(mut self, events: &CallbackEvents) -> Self {
+        self.broadcasts.push(events.clone());
+        self
+    }
+}
+
+/// Fans callback events out to any number of subscribers.
+///
+/// Events published while nobody is subscribed are dropped.
+#[derive(Debug, Clone)]
+pub struct CallbackEvents(broadcast::Sender<EventEnvelope>);
+
+impl CallbackEvents {
+    pub fn new(capacity: usize) -> Self {
+        CallbackEvents(broadcast::channel(capacity).0)
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<EventEnvelope> {
+        self.0.subscribe()
+    }
+
+    pub fn publish(&self, envelope: EventEnvelope) {
+        let _ = self.0.send(envelope);
+    }
+}
+
+impl Default for CallbackEvents {
+    fn default() -> Self {
+        CallbackEvents::new(256)
+    }
+}
+
+impl CallbackReceiver {
+    /// Verifies and dispatches a single callback request, returning the status to answer with.
+    ///
+    /// Use this to mount the receiver in an existing HTTP server instead of [`bind`](Self::bind).
+    pub async fn dispatch(&self, headers: &HeaderMap, body: &[u8]) -> StatusCode {
+        if !self.is_authorized(headers) {
+            warn!("rejected callback with missing or invalid auth header");
+            return StatusCode::UNAUTHORIZED;
+        }
+
+        let envelope = match serde_json::from_slice::<EventEnvelope>(body) {
+            Ok(envelope) => envelope,
+            Err(err) => {
+                warn!(error = %err, "rejected malformed callback event");
+                return StatusCode::BAD_REQUEST;
+            }
+        };
+
+        debug!(id = envelope.id(), kind = %envelope.event().kind(), "received callback event");
+
+        for handler in &self.handlers {
+            handler(envelope.clone()).await;
+        }
+        for channel in &self.channels {
+            if channel.send(envelope.clone()).await.is_err() {
+                debug!("callback channel closed, dropping event");
+            }
+        }
+        for events in &self.broadcasts {
+            events.publish(envelope.clone());
+        }
+
+        StatusCode::NO_CONTENT
+    }
+
+    /// Binds `addr` and serves callbacks in the background until the returned handle is dropped.
+    pub async fn bind(self, addr: impl ToSocketAddrs) -> io::Result<RunningReceiver> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let task = tokio::spawn(self.serve(listener));
+
+        Ok(RunningReceiver { local_addr, task })
+    }
+
+    /// Serves callbacks on `listener` until accepting a connection fails.
+    pub async fn serve(self, listener: TcpListener) -> io::Result<()> {
+        let receiver = Arc::new(self);
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let receiver = receiver.clone();
+
+            tokio::spawn(async move {
+                let service = service_fn(move |request| handle(receiver.clone(), request));
+                if let Err(err) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!(error = %err, "callback connection failed");
+                }
+            });
+        }
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        match &self.auth {
+            Some((auth_key, secret)) => headers
+                .get(auth_key.as_str())
+                .is_some_and(|value| constant_time_eq(value.as_bytes(), secret.as_bytes())),
+            None => true,
+        }
+    }
+}
+
+/// Compares the bytes without short-circuiting, so the time taken doesn't reveal how much of
+/// the secret matched.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+impl fmt::Debug for CallbackReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackReceiver")
+            .field(
+                "auth_key",
+                &self.auth.as_ref().map(|(auth_key, _)| auth_key),
+            )
+            .field("handlers", &self.handlers.len())
+            .field("channels", &self.channels.len())
+            .field("broadcasts", &self.broadcasts.len())
+            .finish()
+    }
+}
+
+async fn handle(
+    receiver: Arc<CallbackReceiver>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let status = if request.method() != Method::POST {
+        StatusCode::METHOD_NOT_ALLOWED
+    } else {
+        let (parts, body) = request.into_parts();
+        match body.collect().await {
+            Ok(body) => receiver.dispatch(&parts.headers, &body.to_bytes()).await,
+            Err(_) => StatusCode::BAD_REQUEST,
+        }
+    };
+
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+    Ok(response)
+}
+
+/// A receiver serving in the background, stopped when dropped.
+#[derive(Debug)]
+pub struct RunningReceiver {
+    local_addr: SocketAddr,
+    task: JoinHandle<io::Result<()>>,
+}
+
+impl RunningReceiver {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn shutdown(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for RunningReceiver {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,126 @@
+use std::{future::Future, time::Duration};
+
+use bon::Builder;
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    time::{timeout_at, Instant},
+};
+use tracing::debug;
+
+use crate::{
+    types::callback_address::CallbackAddress,
+    wait::{self, ProcessState},
+    EdcResult, WaitOptions,
+};
+
+use super::{CallbackEvents, Event, EventEnvelope};
+
+/// Lets the negotiation and transfer waiters complete on callback events instead of polling.
+///
+/// `uri` is where the connector reaches the [`CallbackReceiver`](super::CallbackReceiver) that
+/// publishes to `events`. When no event for the process arrives within `grace_period`, the
+/// waiter falls back to polling.
+#[derive(Debug, Clone, Builder)]
+pub struct CallbackWait {
+    #[builder(field)]
+    auth: Option<(String, String)>,
+    events: CallbackEvents,
+    #[builder(into)]
+    uri: String,
+    #[builder(default = Duration::from_secs(5))]
+    grace_period: Duration,
+}
+
+impl<S: callback_wait_builder::State> CallbackWaitBuilder<S> {
+    pub fn auth(mut self, auth_key: impl Into<String>, auth_code_id: impl Into<String>) -> Self {
+        self.auth = Some((auth_key.into(), auth_code_id.into()));
+        self
+    }
+}
+
+impl CallbackWait {
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
+
+    /// The callback address to register for `event`, e.g. `transfer.process`.
+    pub fn callback_address(&self, event: &str) -> CallbackAddress {
+        let builder = CallbackAddress::builder()
+            .uri(self.uri.clone())
+            .events(vec![event.to_string()]);
+
+        match &self.auth {
+            Some((auth_key, auth_code_id)) => builder.auth(auth_key, auth_code_id).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Subscribes to the events, before initiating the process so none can be missed.
+    pub(crate) fn subscribe(&self) -> CallbackSubscription {
+        CallbackSubscription {
+            events: self.events.subscribe(),
+            grace_period: self.grace_period,
+        }
+    }
+}
+
+pub(crate) struct CallbackSubscription {
+    events: broadcast::Receiver<EventEnvelope>,
+    grace_period: Duration,
+}
+
+impl CallbackSubscription {
+    /// Waits for an event reporting `target` or a state it can't be reached from, then confirms it by polling.
+    ///
+    /// Polling also takes over when no event for `id` arrived within the grace period.
+    pub(crate) async fn wait_for_state<T, S, F, Fut>(
+        mut self,
+        id: &str,
+        target: &S,
+        options: &WaitOptions,
+        event_state: impl Fn(&Event) -> Option<S>,
+        fetch: F,
+        state: impl Fn(&T) -> &S,
+    ) -> EdcResult<T>
+    where
+        S: ProcessState,
+        F: Fn() -> Fut,
+        Fut: Future<Output = EdcResult<T>>,
+    {
+        let deadline = Instant::now() + options.timeout();
+        let mut grace_deadline = Instant::now() + self.grace_period;
+
+        loop {
+            match timeout_at(grace_deadline.min(deadline), self.events.recv()).await {
+                Ok(Ok(envelope)) => match event_state(envelope.event()) {
+                    Some(current) if &current == target || !current.can_reach(target) => {
+                        debug!(id, state = current.name(), "received callback event");
+                        break;
+                    }
+                    Some(current) => {
+                        debug!(id, state = current.name(), "received callback event");
+                        grace_deadline = Instant::now() + self.grace_period;
+                    }
+                    None => {}
+                },
+                Ok(Err(RecvError::Lagged(skipped))) => {
+                    debug!(id, skipped, "missed callback events, polling");
+                    break;
+                }
+                Ok(Err(RecvError::Closed)) | Err(_) => {
+                    debug!(id, "no callback event received, polling");
+                    break;
+                }
+            }
+        }
+
+        let options = WaitOptions::builder()
+            .timeout(deadline.saturating_duration_since(Instant::now()))
+            .poll_interval(options.poll_interval())
+            .backoff(options.backoff())
+            .max_poll_interval(options.max_poll_interval())
+            .build();
+
+        wait::wait_for_state(id, target, &options, fetch, state).await
+    }
+}
@@ -1,7 +1,17 @@
-use std::{future::Future, sync::Arc};
+use std::{
+    future::Future,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use reqwest::{Client, RequestBuilder, Response};
-use serde::{de::DeserializeOwned, Serialize};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    Certificate, Client, Identity, Method, Proxy, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tracing::{field, Instrument, Span};
 
 use crate::{
     api::{
@@ -9,18 +19,21 @@ use crate::{
         DataPlaneApi, EdrApi, ParticipantContextApi, ParticipantContextConfigApi, PolicyApi,
         SecretsApi, TransferProcessApi,
     },
-    error::{
-        BuilderError, ManagementApiError, ManagementApiErrorDetail, ManagementApiErrorDetailKind,
-    },
-    types::context::WithContextRef,
+    error::{BuilderError, ManagementApiError, VersionDetectionError},
+    redact,
+    retry::{RetryEvent, RetryPolicy, RetryReason},
+    transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport},
+    types::{context::WithContextRef, query::Query},
+    workflow::{ConsumerWorkflow, ProviderWorkflow},
     Auth, EdcResult, Error,
 };
 
 #[derive(Clone)]
 pub struct EdcConnectorClient(Arc<EdcConnectorClientInternal>);
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum EdcConnectorApiVersion {
+    #[default]
     V3,
     V4,
 }
@@ -40,66 +53,78 @@ impl EdcConnectorApiVersion {
     }
 }
 
+impl FromStr for EdcConnectorApiVersion {
+    type Err = String;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        match version.to_ascii_lowercase().as_str() {
+            "v3" => Ok(EdcConnectorApiVersion::V3),
+            "v4" | "v4beta" => Ok(EdcConnectorApiVersion::V4),
+            _ => Err(format!(
+                "unsupported management API version {}, expected v3 or v4beta",
+                version
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EdcConnectorApiVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 pub(crate) struct EdcConnectorClientInternal {
-    client: Client,
+    transport: Arc<dyn Transport>,
+    pub(crate) http_client: Option<Client>,
     pub(crate) management_url: String,
     pub(crate) auth: Auth,
     pub(crate) version: EdcConnectorApiVersion,
     pub(crate) participant_context: Option<String>,
+    pub(crate) retry_policy: RetryPolicy,
 }
 
 impl EdcConnectorClientInternal {
     pub(crate) fn new(
-        client: Client,
+        transport: Arc<dyn Transport>,
+        http_client: Option<Client>,
         management_url: String,
         auth: Auth,
         version: EdcConnectorApiVersion,
         participant_context: Option<String>,
+        retry_policy: RetryPolicy,
     ) -> Self {
         Self {
-            client,
+            transport,
+            http_client,
             management_url,
             auth,
             version,
             participant_context,
+            retry_policy,
         }
     }
 
     pub(crate) async fn get<R: DeserializeOwned>(&self, path: impl AsRef<str>) -> EdcResult<R> {
         let response = self
-            .client
-            .get(path.as_ref())
-            .authenticated(&self.auth)
-            .await?
-            .send()
+            .send(Method::GET, path.as_ref(), Option::<&()>::None, true)
             .await?;
 
-        self.handle_response(response, as_json).await
+        as_json(response).await
     }
 
     pub(crate) async fn put(&self, path: impl AsRef<str>, body: &impl Serialize) -> EdcResult<()> {
-        let response = self
-            .client
-            .put(path.as_ref())
-            .json(body)
-            .authenticated(&self.auth)
-            .await?
-            .send()
-            .await?;
-
-        self.handle_response(response, empty).await
+        self.internal_put(path, body, empty).await
     }
 
     pub(crate) async fn del(&self, path: impl AsRef<str>) -> EdcResult<()> {
         let response = self
-            .client
-            .delete(path.as_ref())
-            .authenticated(&self.auth)
-            .await?
-            .send()
+            .send(Method::DELETE, path.as_ref(), Option::<&()>::None, true)
             .await?;
 
-        self.handle_response(response, empty).await
+        empty(response).await
     }
 
     pub(crate) async fn post<I: Serialize, R: DeserializeOwned>(
@@ -107,7 +132,15 @@ impl EdcConnectorClientInternal {
         path: impl AsRef<str>,
         body: &I,
     ) -> EdcResult<R> {
-        self.internal_post(path, body, as_json).await
+        self.internal_post(path, body, false, as_json).await
+    }
+
+    pub(crate) async fn post_idempotent<I: Serialize, R: DeserializeOwned>(
+        &self,
+        path: impl AsRef<str>,
+        body: &I,
+    ) -> EdcResult<R> {
+        self.internal_post(path, body, true, as_json).await
     }
 
     pub(crate) async fn put_no_response<I: Serialize>(
@@ -123,7 +156,29 @@ impl EdcConnectorClientInternal {
         path: impl AsRef<str>,
         body: &I,
     ) -> EdcResult<()> {
-        self.internal_post(path, body, empty).await
+        self.internal_post(path, body, false, empty).await
+    }
+
+    pub(crate) async fn post_without_body<R: DeserializeOwned>(
+        &self,
+        path: impl AsRef<str>,
+    ) -> EdcResult<R> {
+        let response = self
+            .send(Method::POST, path.as_ref(), Option::<&()>::None, false)
+            .await?;
+
+        as_json(response).await
+    }
+
+    pub(crate) async fn post_without_body_no_response(
+        &self,
+        path: impl AsRef<str>,
+    ) -> EdcResult<()> {
+        let response = self
+            .send(Method::POST, path.as_ref(), Option::<&()>::None, false)
+            .await?;
+
+        empty(response).await
     }
 
     async fn internal_put<I, F, Fut, R>(
@@ -134,64 +189,176 @@ impl EdcConnectorClientInternal {
     ) -> EdcResult<R>
     where
         I: Serialize,
-        F: Fn(Response) -> Fut,
+        F: Fn(HttpResponse) -> Fut,
         Fut: Future<Output = EdcResult<R>>,
     {
         let response = self
-            .client
-            .put(path.as_ref())
-            .json(body)
-            .authenticated(&self.auth)
-            .await?
-            .send()
+            .send(Method::PUT, path.as_ref(), Some(body), true)
             .await?;
 
-        self.handle_response(response, handler).await
+        handler(response).await
     }
 
     async fn internal_post<I, F, Fut, R>(
         &self,
         path: impl AsRef<str>,
         body: &I,
+        idempotent: bool,
         handler: F,
     ) -> EdcResult<R>
     where
         I: Serialize,
-        F: Fn(Response) -> Fut,
+        F: Fn(HttpResponse) -> Fut,
         Fut: Future<Output = EdcResult<R>>,
     {
         let response = self
-            .client
-            .post(path.as_ref())
-            .json(body)
-            .authenticated(&self.auth)
-            .await?
-            .send()
+            .send(Method::POST, path.as_ref(), Some(body), idempotent)
             .await?;
 
-        self.handle_response(response, handler).await
+        handler(response).await
     }
 
-    async fn handle_response<F, Fut, R>(&self, response: Response, handler: F) -> EdcResult<R>
-    where
-        F: Fn(Response) -> Fut,
-        Fut: Future<Output = EdcResult<R>>,
-    {
-        if response.status().is_success() {
-            handler(response).await
-        } else {
-            let status = response.status();
-            let text = response.text().await?;
+    async fn send<I: Serialize>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&I>,
+        idempotent: bool,
+    ) -> EdcResult<HttpResponse> {
+        let body = body.map(serde_json::to_value).transpose()?;
+        let span = tracing::debug_span!(
+            "edc.request",
+            http.method = %method,
+            url = %url,
+            participant_context = self.participant_context.as_deref(),
+            status = field::Empty,
+            latency_ms = field::Empty,
+            retries = field::Empty,
+        );
+
+        async move {
+            let started = Instant::now();
+            let (result, retries) = self
+                .send_with_retries(method.clone(), url, body, idempotent)
+                .await;
+
+            let span = Span::current();
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+            span.record("retries", retries);
+            match &result {
+                Ok(response) => {
+                    span.record("status", response.status.as_u16());
+                    tracing::trace!(body = %redact::body(&response.body), "received response");
+                    tracing::debug!("management api call completed");
+                }
+                Err(err) => tracing::debug!(error = %err, "management api call failed"),
+            }
+
+            let response = result?;
+            if response.status.is_success() {
+                Ok(response)
+            } else {
+                Err(Error::ManagementApi(ManagementApiError::from_response(
+                    method, url, response,
+                )))
+            }
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<Value>,
+        idempotent: bool,
+    ) -> (EdcResult<HttpResponse>, u32) {
+        let mut attempt = 1;
+        let mut reauthenticated = false;
+        loop {
+            let request = HttpRequest {
+                method: method.clone(),
+                url: url.to_string(),
+                headers: HeaderMap::new(),
+                body: body.clone(),
+            }
+            .authenticated(&self.auth)
+            .await;
 
-            let err = match serde_json::from_str::<Vec<ManagementApiErrorDetail>>(&text) {
-                Ok(parsed) => ManagementApiErrorDetailKind::Parsed(parsed),
-                Err(_) => ManagementApiErrorDetailKind::Raw(text),
+            let request = match request {
+                Ok(request) => request,
+                Err(err) => return (Err(err), attempt - 1),
             };
 
-            Err(Error::ManagementApi(ManagementApiError {
-                status_code: status,
-                error_detail: err,
-            }))
+            tracing::trace!(
+                headers = ?redact::headers(&request.headers),
+                body = request.body.as_ref().map(redact::json).map(tracing::field::display),
+                "sending request"
+            );
+
+            let authorization = request.headers.get(AUTHORIZATION).cloned();
+            let result = self.transport.send(request).await;
+
+            if matches!(&result, Ok(response) if response.status == StatusCode::UNAUTHORIZED)
+                && !reauthenticated
+                && self.auth.invalidate(authorization.as_ref()).await
+            {
+                tracing::debug!(
+                    "management api rejected the credentials, retrying with fresh ones"
+                );
+                reauthenticated = true;
+                continue;
+            }
+
+            let reason = match &result {
+                Ok(response) if self.retry_policy.is_retryable_status(response.status) => {
+                    RetryReason::Status(response.status)
+                }
+                Err(err) if err.is_transient() => RetryReason::Transport(err.to_string()),
+                _ => return (result, attempt - 1),
+            };
+
+            if !self.retry_policy.allows(attempt, idempotent) {
+                return (result, attempt - 1);
+            }
+
+            let delay = self.retry_policy.backoff(attempt);
+            tracing::debug!(attempt, delay_ms = delay.as_millis() as u64, reason = %reason, "retrying management api call");
+            self.retry_policy.notify(&RetryEvent {
+                method: method.clone(),
+                url: url.to_string(),
+                attempt,
+                delay,
+                reason,
+            });
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn with_version(&self, version: EdcConnectorApiVersion) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            http_client: self.http_client.clone(),
+            management_url: self.management_url.clone(),
+            auth: self.auth.clone(),
+            version,
+            participant_context: self.participant_context.clone(),
+            retry_policy: self.retry_policy.clone(),
+        }
+    }
+
+    fn with_participant_context(&self, participant_context: Option<String>) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            http_client: self.http_client.clone(),
+            management_url: self.management_url.clone(),
+            auth: self.auth.clone(),
+            version: self.version.clone(),
+            participant_context,
+            retry_policy: self.retry_policy.clone(),
         }
     }
 
@@ -242,28 +409,32 @@ impl EdcConnectorClientInternal {
     }
 }
 
-async fn as_json<R: DeserializeOwned>(response: Response) -> EdcResult<R> {
-    response.json().await.map(Ok)?
+async fn as_json<R: DeserializeOwned>(response: HttpResponse) -> EdcResult<R> {
+    Ok(serde_json::from_slice(&response.body)?)
 }
 
-async fn empty(_response: Response) -> EdcResult<()> {
+async fn empty(_response: HttpResponse) -> EdcResult<()> {
     Ok(())
 }
 
 impl EdcConnectorClient {
     pub(crate) fn new(
-        client: Client,
+        transport: Arc<dyn Transport>,
+        http_client: Option<Client>,
         management_url: String,
         auth: Auth,
         version: EdcConnectorApiVersion,
         participant_context: Option<String>,
+        retry_policy: RetryPolicy,
     ) -> Self {
         Self(Arc::new(EdcConnectorClientInternal::new(
-            client,
+            transport,
+            http_client,
             management_url,
             auth,
             version,
             participant_context,
+            retry_policy,
         )))
     }
 
@@ -271,6 +442,11 @@ impl EdcConnectorClient {
         EdcClientConnectorBuilder::default()
     }
 
+    /// The HTTP client behind the default transport, `None` with a custom transport.
+    pub(crate) fn http_client(&self) -> Option<&Client> {
+        self.0.http_client.as_ref()
+    }
+
     pub fn assets(&self) -> AssetApi<'_> {
         AssetApi::new(&self.0)
     }
@@ -319,9 +495,76 @@ impl EdcConnectorClient {
         ParticipantContextConfigApi::new(&self.0)
     }
 
+    pub fn consumer(&self) -> ConsumerWorkflow<'_> {
+        ConsumerWorkflow::new(self)
+    }
+
+    pub fn provider(&self) -> ProviderWorkflow<'_> {
+        ProviderWorkflow::new(self)
+    }
+
     pub fn api_version(&self) -> EdcConnectorApiVersion {
         self.0.version.clone()
     }
+
+    pub fn participant_context(&self) -> Option<&str> {
+        self.0.participant_context.as_deref()
+    }
+
+    /// Returns a client scoped to another participant context.
+    ///
+    /// The returned client shares the transport, the authentication (including any cached OAuth2
+    /// session) and the retry policy with this one; only the participant segment of the
+    /// management API paths changes.
+    pub fn for_participant(&self, participant_context: impl Into<String>) -> EdcConnectorClient {
+        EdcConnectorClient(Arc::new(
+            self.0
+                .with_participant_context(Some(participant_context.into())),
+        ))
+    }
+
+    /// Probes the connector with a cheap asset query for each known management API version,
+    /// newest first, and returns the first one that is served.
+    ///
+    /// Clients scoped to a participant context always use the participant paths, which only the
+    /// v4 management API serves, so no probing happens and v4 is returned.
+    #[tracing::instrument(level = "debug", skip_all, fields(management_url = %self.0.management_url))]
+    pub async fn detect_version(&self) -> EdcResult<EdcConnectorApiVersion> {
+        if self.0.participant_context.is_some() {
+            return Ok(EdcConnectorApiVersion::V4);
+        }
+
+        let mut attempts = vec![];
+
+        for version in [EdcConnectorApiVersion::V4, EdcConnectorApiVersion::V3] {
+            let probe = self.0.with_version(version.clone());
+            let query = Query::builder().limit(1).build();
+
+            match AssetApi::new(&probe).query(query).await {
+                Ok(_) => return Ok(version),
+                Err(err) if is_unsupported_version(&err) => {
+                    tracing::debug!(version = version.as_str(), error = %err, "management api version not served");
+                    attempts.push((version, err.to_string()));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(VersionDetectionError {
+            management_url: self.0.management_url.clone(),
+            attempts,
+        }
+        .into())
+    }
+}
+
+fn is_unsupported_version(err: &Error) -> bool {
+    err.management_api_error().is_some_and(|err| {
+        matches!(
+            err.status_code,
+            StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED
+        )
+    })
 }
 
 pub struct EdcClientConnectorBuilder {
@@ -329,6 +572,15 @@ pub struct EdcClientConnectorBuilder {
     auth: Auth,
     version: EdcConnectorApiVersion,
     participant_context: Option<String>,
+    retry_policy: RetryPolicy,
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn Transport>>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    identity: Option<Identity>,
 }
 
 impl EdcClientConnectorBuilder {
@@ -360,45 +612,176 @@ impl EdcClientConnectorBuilder {
         self
     }
 
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
     pub fn build(self) -> Result<EdcConnectorClient, BuilderError> {
+        let client = self.build_http_client()?;
         let url = self
             .management_url
             .ok_or_else(|| BuilderError::missing_property("management_url"))?;
-        let client = Client::new();
+
+        self.auth.bind_http_client(&client);
+
+        let (transport, http_client) = match self.transport {
+            Some(transport) => (transport, None),
+            None => (
+                Arc::new(ReqwestTransport::new(client.clone())) as Arc<dyn Transport>,
+                Some(client),
+            ),
+        };
 
         Ok(EdcConnectorClient::new(
-            client,
+            transport,
+            http_client,
             url,
             self.auth,
             self.version,
             self.participant_context,
+            self.retry_policy,
         ))
     }
 }
 
+impl EdcClientConnectorBuilder {
+    /// Builds the client and configures it with the management API version served by the
+    /// connector, see [`EdcConnectorClient::detect_version`].
+    pub async fn build_detected(self) -> EdcResult<EdcConnectorClient> {
+        let client = self.build()?;
+        let version = client.detect_version().await?;
+
+        Ok(EdcConnectorClient(Arc::new(client.0.with_version(version))))
+    }
+
+    fn has_transport_options(&self) -> bool {
+        self.timeout.is_some()
+            || self.connect_timeout.is_some()
+            || self.user_agent.is_some()
+            || !self.proxies.is_empty()
+            || !self.root_certificates.is_empty()
+            || self.identity.is_some()
+    }
+
+    fn build_http_client(&self) -> Result<Client, BuilderError> {
+        if let Some(client) = &self.http_client {
+            if self.has_transport_options() {
+                return Err(BuilderError::invalid_property(
+                    "http_client",
+                    "a preconfigured client cannot be combined with timeout, proxy, TLS or user agent options",
+                ));
+            }
+            return Ok(client.clone());
+        }
+
+        let mut builder = Client::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.clone());
+        }
+
+        builder.build().map_err(BuilderError::HttpClient)
+    }
+}
+
 impl Default for EdcClientConnectorBuilder {
     fn default() -> Self {
         Self {
             management_url: Default::default(),
             auth: Auth::NoAuth,
-            version: EdcConnectorApiVersion::V3,
+            version: EdcConnectorApiVersion::default(),
             participant_context: None,
+            retry_policy: RetryPolicy::default(),
+            http_client: None,
+            transport: None,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            identity: None,
         }
     }
 }
 
-trait BuilderExt: Sized {
+trait RequestExt: Sized {
     fn authenticated(self, auth: &Auth) -> impl Future<Output = EdcResult<Self>>;
 }
 
-impl BuilderExt for RequestBuilder {
-    async fn authenticated(self, auth: &Auth) -> EdcResult<Self> {
-        match auth {
-            Auth::NoAuth => Ok(self),
-            Auth::ApiToken(token) => Ok(self.header("X-Api-Key", token)),
+impl RequestExt for HttpRequest {
+    async fn authenticated(mut self, auth: &Auth) -> EdcResult<Self> {
+        let header = match auth {
+            Auth::NoAuth => None,
+            Auth::ApiToken(token) => Some((HeaderName::from_static("x-api-key"), token.clone())),
             Auth::OAuth2(client) => {
-                Ok(self.header("Authorization", format!("Bearer {}", client.token().await?)))
+                Some((AUTHORIZATION, format!("Bearer {}", client.token().await?)))
             }
+            Auth::Custom(provider) => {
+                provider.authenticate(&mut self).await?;
+                None
+            }
+        };
+
+        if let Some((name, value)) = header {
+            let value = HeaderValue::from_str(&value).map_err(|e| Error::Auth(Box::new(e)))?;
+            self.headers.insert(name, value);
         }
+
+        Ok(self)
     }
 }
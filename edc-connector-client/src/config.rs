@@ -0,0 +1,349 @@
+use std::{collections::BTreeMap, env, fmt, fs, path::Path, str::FromStr, time::Duration};
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_with::{serde_as, DurationMilliSeconds};
+
+use crate::{
+    client::EdcClientConnectorBuilder, Auth, BuilderError, EdcConnectorApiVersion, OAuth2Config,
+    RetryPolicy,
+};
+
+/// Serializable settings for [`EdcConnectorClient`](crate::EdcConnectorClient).
+///
+/// It can be loaded from JSON, from TOML with the `toml` feature, or from environment
+/// variables with [`EdcClientConfig::from_env`].
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EdcClientConfig {
+    pub management_url: String,
+    #[serde(default)]
+    pub version: EdcConnectorApiVersion,
+    pub participant_context: Option<String>,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub timeout_ms: Option<Duration>,
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub connect_timeout_ms: Option<Duration>,
+    pub user_agent: Option<String>,
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AuthConfig {
+    #[default]
+    None,
+    ApiToken {
+        token: String,
+    },
+    Bearer {
+        token: String,
+    },
+    Basic {
+        username: String,
+        password: String,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        client_id: String,
+        client_secret: Option<String>,
+        token_url: Option<String>,
+        issuer_url: Option<String>,
+        scopes: Option<Vec<String>>,
+        #[serde(default)]
+        extra_params: BTreeMap<String, String>,
+    },
+}
+
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthConfig::None => f.write_str("None"),
+            AuthConfig::ApiToken { .. } => f
+                .debug_struct("ApiToken")
+                .field("token", &"<redacted>")
+                .finish(),
+            AuthConfig::Bearer { .. } => f
+                .debug_struct("Bearer")
+                .field("token", &"<redacted>")
+                .finish(),
+            AuthConfig::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            AuthConfig::OAuth2 {
+                client_id,
+                client_secret,
+                token_url,
+                issuer_url,
+                scopes,
+                extra_params,
+            } => f
+                .debug_struct("OAuth2")
+                .field("client_id", client_id)
+                .field(
+                    "client_secret",
+                    &client_secret.as_ref().map(|_| "<redacted>"),
+                )
+                .field("token_url", token_url)
+                .field("issuer_url", issuer_url)
+                .field("scopes", scopes)
+                .field("extra_params", &extra_params.keys())
+                .finish(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    pub max_attempts: Option<u32>,
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub initial_backoff_ms: Option<Duration>,
+    #[serde_as(as = "Option<DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    pub max_backoff_ms: Option<Duration>,
+    pub multiplier: Option<f64>,
+    pub jitter: Option<bool>,
+    pub retryable_status_codes: Option<Vec<u16>>,
+    pub retry_non_idempotent: Option<bool>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read the config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid JSON config: {0}")]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "toml")]
+    #[error("Invalid TOML config: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Unsupported config file format: {0}")]
+    UnsupportedFormat(String),
+}
+
+impl EdcClientConfig {
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Loads the config from a `.json` file, or from a `.toml` file with the `toml` feature.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&content),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&content),
+            ext => Err(ConfigError::UnsupportedFormat(
+                ext.unwrap_or_default().to_string(),
+            )),
+        }
+    }
+
+    /// Reads the config from environment variables named `{prefix}_{SETTING}`.
+    ///
+    /// The supported settings are `MANAGEMENT_URL`, `API_VERSION`, `PARTICIPANT_CONTEXT`,
+    /// `API_TOKEN`, `OAUTH2_CLIENT_ID`, `OAUTH2_CLIENT_SECRET`, `OAUTH2_TOKEN_URL`,
+    /// `OAUTH2_ISSUER_URL`, `OAUTH2_SCOPES` (comma separated), `TIMEOUT_MS`,
+    /// `CONNECT_TIMEOUT_MS`, `USER_AGENT`, `RETRY_MAX_ATTEMPTS`, `RETRY_INITIAL_BACKOFF_MS`
+    /// and `RETRY_MAX_BACKOFF_MS`.
+    pub fn from_env(prefix: &str) -> Result<Self, BuilderError> {
+        Self::from_vars(prefix, |name| env::var(name).ok())
+    }
+
+    fn from_vars(
+        prefix: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, BuilderError> {
+        let vars = EnvVars { prefix, lookup };
+
+        let auth = if let Some(client_id) = vars.get("OAUTH2_CLIENT_ID") {
+            AuthConfig::OAuth2 {
+                client_id,
+                client_secret: vars.get("OAUTH2_CLIENT_SECRET"),
+                token_url: vars.get("OAUTH2_TOKEN_URL"),
+                issuer_url: vars.get("OAUTH2_ISSUER_URL"),
+                scopes: vars.get("OAUTH2_SCOPES").map(|scopes| {
+                    scopes
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|scope| !scope.is_empty())
+                        .map(String::from)
+                        .collect()
+                }),
+                extra_params: BTreeMap::new(),
+            }
+        } else if let Some(token) = vars.get("API_TOKEN") {
+            AuthConfig::ApiToken { token }
+        } else {
+            AuthConfig::None
+        };
+
+        let retry = RetryConfig {
+            max_attempts: vars.parse("RETRY_MAX_ATTEMPTS")?,
+            initial_backoff_ms: vars
+                .parse("RETRY_INITIAL_BACKOFF_MS")?
+                .map(Duration::from_millis),
+            max_backoff_ms: vars
+                .parse("RETRY_MAX_BACKOFF_MS")?
+                .map(Duration::from_millis),
+            ..Default::default()
+        };
+
+        Ok(EdcClientConfig {
+            management_url: vars
+                .get("MANAGEMENT_URL")
+                .ok_or_else(|| BuilderError::missing_property(&vars.name("MANAGEMENT_URL")))?,
+            version: vars.parse("API_VERSION")?.unwrap_or_default(),
+            participant_context: vars.get("PARTICIPANT_CONTEXT"),
+            auth,
+            timeout_ms: vars.parse("TIMEOUT_MS")?.map(Duration::from_millis),
+            connect_timeout_ms: vars.parse("CONNECT_TIMEOUT_MS")?.map(Duration::from_millis),
+            user_agent: vars.get("USER_AGENT"),
+            retry: retry.is_set().then_some(retry),
+        })
+    }
+}
+
+struct EnvVars<'a, F> {
+    prefix: &'a str,
+    lookup: F,
+}
+
+impl<F: Fn(&str) -> Option<String>> EnvVars<'_, F> {
+    fn name(&self, setting: &str) -> String {
+        if self.prefix.is_empty() {
+            setting.to_string()
+        } else {
+            format!("{}_{}", self.prefix, setting)
+        }
+    }
+
+    fn get(&self, setting: &str) -> Option<String> {
+        (self.lookup)(&self.name(setting)).filter(|value| !value.is_empty())
+    }
+
+    fn parse<T>(&self, setting: &str) -> Result<Option<T>, BuilderError>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        self.get(setting)
+            .map(|value| {
+                value.parse().map_err(|err: T::Err| {
+                    BuilderError::invalid_property(&self.name(setting), err.to_string())
+                })
+            })
+            .transpose()
+    }
+}
+
+impl AuthConfig {
+    fn into_auth(self) -> Result<Auth, BuilderError> {
+        Ok(match self {
+            AuthConfig::None => Auth::NoAuth,
+            AuthConfig::ApiToken { token } => Auth::api_token(token),
+            AuthConfig::Bearer { token } => Auth::bearer(token),
+            AuthConfig::Basic { username, password } => Auth::basic(username, password),
+            AuthConfig::OAuth2 {
+                client_id,
+                client_secret,
+                token_url,
+                issuer_url,
+                scopes,
+                extra_params,
+            } => {
+                let mut builder = OAuth2Config::builder()
+                    .client_id(client_id)
+                    .maybe_client_secret(client_secret)
+                    .maybe_token_url(token_url)
+                    .maybe_issuer_url(issuer_url)
+                    .maybe_scopes(scopes);
+
+                for (name, value) in extra_params {
+                    builder = builder.extra_param(name, value);
+                }
+
+                Auth::oauth(builder.build())
+                    .map_err(|err| BuilderError::invalid_property("auth", err.to_string()))?
+            }
+        })
+    }
+}
+
+impl RetryConfig {
+    fn is_set(&self) -> bool {
+        self.max_attempts.is_some()
+            || self.initial_backoff_ms.is_some()
+            || self.max_backoff_ms.is_some()
+    }
+
+    fn into_policy(self) -> Result<RetryPolicy, BuilderError> {
+        let retryable_status_codes = self
+            .retryable_status_codes
+            .map(|codes| {
+                codes
+                    .into_iter()
+                    .map(StatusCode::from_u16)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .map_err(|err| {
+                BuilderError::invalid_property("retryable_status_codes", err.to_string())
+            })?;
+
+        Ok(RetryPolicy::builder()
+            .maybe_max_attempts(self.max_attempts)
+            .maybe_initial_backoff(self.initial_backoff_ms)
+            .maybe_max_backoff(self.max_backoff_ms)
+            .maybe_multiplier(self.multiplier)
+            .maybe_jitter(self.jitter)
+            .maybe_retryable_status_codes(retryable_status_codes)
+            .maybe_retry_non_idempotent(self.retry_non_idempotent)
+            .build())
+    }
+}
+
+impl EdcClientConnectorBuilder {
+    pub fn from_config(config: EdcClientConfig) -> Result<Self, BuilderError> {
+        let mut builder = EdcClientConnectorBuilder::default()
+            .management_url(config.management_url)
+            .version(config.version)
+            .maybe_participant_context(config.participant_context)
+            .with_auth(config.auth.into_auth()?);
+
+        if let Some(timeout) = config.timeout_ms {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = config.connect_timeout_ms {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = config.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(retry) = config.retry {
+            builder = builder.retry_policy(retry.into_policy()?);
+        }
+
+        Ok(builder)
+    }
+
+    pub fn from_env(prefix: &str) -> Result<Self, BuilderError> {
+        Self::from_config(EdcClientConfig::from_env(prefix)?)
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fmt,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use bon::Builder;
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION, CONTENT_TYPE},
+    Client, Method, Response, StatusCode,
+};
+use serde::de::DeserializeOwned;
+use tracing::{debug, instrument};
+
+use crate::{
+    types::data_address::DataAddress, EdcConnectorClient, EdcResult, EdrCache, EdrKey, Error,
+};
+
+/// The data address type of EDRs served by EDC's HTTP data plane.
+pub const HTTP_DATA_ADDRESS_TYPE: &str = "https://w3id.org/idsa/v4.1/HTTP";
+
+#[derive(Debug, thiserror::Error)]
+pub enum DataPlaneError {
+    #[error("Unsupported data address type {0}, expected {HTTP_DATA_ADDRESS_TYPE}")]
+    UnsupportedType(String),
+    #[error("Data address has no endpoint")]
+    MissingEndpoint,
+    #[error("Data plane request {method} {url} failed with status {status}: {body}")]
+    Status {
+        method: Method,
+        url: String,
+        status: StatusCode,
+        body: String,
+    },
+}
+
+/// A request to the data plane, relative to the EDR endpoint.
+///
+/// The path, query parameters, method and body are only forwarded to the provider's backend when
+/// the asset enables `proxyPath`, `proxyQueryParams`, `proxyMethod` and `proxyBody`.
+#[derive(Debug, Clone, Builder)]
+pub struct DataPlaneRequest {
+    #[builder(field)]
+    query: Vec<(String, String)>,
+    #[builder(default = Method::GET)]
+    method: Method,
+    #[builder(into)]
+    path: Option<String>,
+    #[builder(into)]
+    body: Option<Bytes>,
+    #[builder(into)]
+    content_type: Option<String>,
+}
+
+impl<S: data_plane_request_builder::State> DataPlaneRequestBuilder<S> {
+    pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Default for DataPlaneRequest {
+    fn default() -> Self {
+        DataPlaneRequest::builder().build()
+    }
+}
+
+enum Refresher {
+    Edr {
+        client: EdcConnectorClient,
+        transfer_process_id: String,
+    },
+    Cache {
+        cache: Arc<EdrCache>,
+        key: EdrKey,
+    },
+}
+
+/// Pulls data from a provider's data plane through an EDR.
+///
+/// When a refresh source is configured, a `401` from the data plane triggers a token refresh and
+/// the request is sent once more.
+pub struct DataPlaneClient {
+    http: Client,
+    data_address: RwLock<DataAddress>,
+    refresher: Option<Refresher>,
+}
+
+impl DataPlaneClient {
+    /// Creates a client with a default HTTP client, see [`DataPlaneClient::with_http_client`].
+    pub fn new(data_address: DataAddress) -> Result<Self, DataPlaneError> {
+        validate(&data_address)?;
+
+        Ok(DataPlaneClient {
+            http: Client::default(),
+            data_address: RwLock::new(data_address),
+            refresher: None,
+        })
+    }
+
+    /// Creates a client from the EDR cached for `key`, refreshing through the cache.
+    ///
+    /// Requests go through the HTTP client of the cache's management client when it uses the
+    /// default transport.
+    pub async fn from_cache(cache: Arc<EdrCache>, key: EdrKey) -> EdcResult<Self> {
+        let edr = cache.get(&key).await?;
+        let mut client = DataPlaneClient::new(edr.data_address().clone())?;
+
+        if let Some(http) = cache.client().http_client() {
+            client.http = http.clone();
+        }
+
+        Ok(DataPlaneClient {
+            refresher: Some(Refresher::Cache { cache, key }),
+            ..client
+        })
+    }
+
+    pub fn with_http_client(mut self, http: Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Refreshes the EDR of `transfer_process_id` through the management API when the data plane
+    /// rejects the token.
+    ///
+    /// Like [`DataPlaneClient::from_cache`], this reuses the HTTP client of `client` when it uses
+    /// the default transport; call [`DataPlaneClient::with_http_client`] afterwards to override it.
+    pub fn with_edr_refresh(
+        mut self,
+        client: EdcConnectorClient,
+        transfer_process_id: impl Into<String>,
+    ) -> Self {
+        if let Some(http) = client.http_client() {
+            self.http = http.clone();
+        }
+        self.refresher = Some(Refresher::Edr {
+            client,
+            transfer_process_id: transfer_process_id.into(),
+        });
+        self
+    }
+
+    pub fn data_address(&self) -> DataAddress {
+        self.data_address
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub async fn get(&self, path: &str) -> EdcResult<DataPlaneResponse> {
+        self.send(DataPlaneRequest::builder().path(path).build())
+            .await
+    }
+
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(api = "data_plane", method = %request.method, path = request.path.as_deref().unwrap_or(""))
+    )]
+    pub async fn send(&self, request: DataPlaneRequest) -> EdcResult<DataPlaneResponse> {
+        let mut response = self.execute(&request).await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED && self.refresher.is_some() {
+            debug!("data plane rejected the token, refreshing the EDR");
+            self.refresh().await?;
+            response = self.execute(&request).await?;
+        }
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(DataPlaneResponse(response))
+        } else {
+            let url = response.url().to_string();
+            let body = response.text().await.unwrap_or_default();
+            Err(Error::DataPlane(DataPlaneError::Status {
+                method: request.method,
+                url,
+                status,
+                body,
+            }))
+        }
+    }
+
+    /// Fetches a new token, without waiting for the data plane to reject the current one.
+    pub async fn refresh(&self) -> EdcResult<()> {
+        let data_address = match &self.refresher {
+            Some(Refresher::Edr {
+                client,
+                transfer_process_id,
+            }) => client.edrs().refresh(transfer_process_id).await?,
+            Some(Refresher::Cache { cache, key }) => {
+                cache.refresh(key).await?.data_address().clone()
+            }
+            None => return Ok(()),
+        };
+        validate(&data_address)?;
+
+        *self
+            .data_address
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = data_address;
+        Ok(())
+    }
+
+    async fn execute(&self, request: &DataPlaneRequest) -> EdcResult<Response> {
+        let (url, authorization) = {
+            let data_address = self
+                .data_address
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            let endpoint = data_address
+                .endpoint()
+                .ok_or(DataPlaneError::MissingEndpoint)?;
+            (
+                join(endpoint, request.path.as_deref()),
+                data_address.authorization().map(String::from),
+            )
+        };
+
+        let mut builder = self.http.request(request.method.clone(), &url);
+        if !request.query.is_empty() {
+            builder = builder.query(&request.query);
+        }
+        if let Some(authorization) = authorization {
+            builder = builder.header(AUTHORIZATION, authorization);
+        }
+        if let Some(content_type) = &request.content_type {
+            builder = builder.header(CONTENT_TYPE, content_type);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(body.clone());
+        }
+
+        Ok(builder.send().await?)
+    }
+}
+
+impl fmt::Debug for DataPlaneClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataPlaneClient")
+            .field("endpoint", &self.data_address().endpoint())
+            .field("refresh", &self.refresher.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The response of the data plane, with the body not read yet.
+#[derive(Debug)]
+pub struct DataPlaneResponse(Response);
+
+impl DataPlaneResponse {
+    pub fn status(&self) -> StatusCode {
+        self.0.status()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.0.headers()
+    }
+
+    pub fn content_length(&self) -> Option<u64> {
+        self.0.content_length()
+    }
+
+    pub fn bytes_stream(self) -> impl Stream<Item = EdcResult<Bytes>> {
+        self.0.bytes_stream().map_err(Error::from)
+    }
+
+    pub async fn bytes(self) -> EdcResult<Bytes> {
+        Ok(self.0.bytes().await?)
+    }
+
+    pub async fn text(self) -> EdcResult<String> {
+        Ok(self.0.text().await?)
+    }
+
+    pub async fn json<T: DeserializeOwned>(self) -> EdcResult<T> {
+        Ok(serde_json::from_slice(&self.0.bytes().await?)?)
+    }
+}
+
+fn validate(data_address: &DataAddress) -> Result<(), DataPlaneError> {
+    match data_address.kind().or(data_address.endpoint_type()) {
+        Some(HTTP_DATA_ADDRESS_TYPE) => {}
+        other => {
+            return Err(DataPlaneError::UnsupportedType(
+                other.unwrap_or_default().to_string(),
+            ))
+        }
+    }
+
+    data_address
+        .endpoint()
+        .map(|_| ())
+        .ok_or(DataPlaneError::MissingEndpoint)
+}
+
+fn join(endpoint: &str, path: Option<&str>) -> String {
+    match path.map(|path| path.trim_start_matches('/')) {
+        Some(path) if !path.is_empty() => format!("{}/{}", endpoint.trim_end_matches('/'), path),
+        _ => endpoint.to_string(),
+    }
+}
@@ -0,0 +1,334 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use bon::Builder;
+use tokio::{sync::Mutex as AsyncMutex, time::Instant};
+use tracing::{debug, instrument};
+
+use crate::{types::data_address::DataAddress, EdcConnectorClient, EdcResult};
+
+/// Identifies a cached EDR.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EdrKey {
+    TransferProcess(String),
+    Asset {
+        asset_id: String,
+        provider_id: String,
+    },
+}
+
+impl EdrKey {
+    pub fn transfer_process(id: impl Into<String>) -> Self {
+        EdrKey::TransferProcess(id.into())
+    }
+
+    pub fn asset(asset_id: impl Into<String>, provider_id: impl Into<String>) -> Self {
+        EdrKey::Asset {
+            asset_id: asset_id.into(),
+            provider_id: provider_id.into(),
+        }
+    }
+}
+
+impl fmt::Display for EdrKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EdrKey::TransferProcess(id) => write!(f, "transfer process {}", id),
+            EdrKey::Asset {
+                asset_id,
+                provider_id,
+            } => write!(f, "asset {} from {}", asset_id, provider_id),
+        }
+    }
+}
+
+/// An EDR served by the [`EdrCache`].
+#[derive(Clone)]
+pub struct CachedEdr {
+    transfer_process_id: String,
+    data_address: DataAddress,
+    expires_at: Option<Instant>,
+}
+
+impl CachedEdr {
+    fn new(transfer_process_id: String, data_address: DataAddress) -> Self {
+        let expires_at = data_address
+            .expires_in()
+            .map(|expires_in| Instant::now() + expires_in);
+
+        CachedEdr {
+            transfer_process_id,
+            data_address,
+            expires_at,
+        }
+    }
+
+    pub fn transfer_process_id(&self) -> &str {
+        &self.transfer_process_id
+    }
+
+    pub fn endpoint(&self) -> Option<&str> {
+        self.data_address.endpoint()
+    }
+
+    pub fn authorization(&self) -> Option<&str> {
+        self.data_address.authorization()
+    }
+
+    pub fn data_address(&self) -> &DataAddress {
+        &self.data_address
+    }
+
+    /// When the token expires, if the connector told us.
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    fn expires_within(&self, window: Duration) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Instant::now() + window)
+    }
+}
+
+impl fmt::Debug for CachedEdr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedEdr")
+            .field("transfer_process_id", &self.transfer_process_id)
+            .field("endpoint", &self.endpoint())
+            .field("expires_at", &self.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Caches EDR data addresses so repeated pulls don't hit the management API every time.
+///
+/// Entries are refreshed once they get within `refresh_before` of their expiry, and evicted when
+/// the EDR is deleted or the transfer process has finished. Concurrent callers needing a token
+/// for the same transfer process share a single load or refresh, as refresh tokens are usually
+/// single-use.
+#[derive(Builder)]
+pub struct EdrCache {
+    #[builder(start_fn)]
+    client: EdcConnectorClient,
+    #[builder(default = Duration::from_secs(30))]
+    refresh_before: Duration,
+    #[builder(skip)]
+    entries: Mutex<HashMap<EdrKey, CachedEdr>>,
+    #[builder(skip)]
+    refreshes: Mutex<HashMap<String, Arc<AsyncMutex<()>>>>,
+}
+
+impl EdrCache {
+    pub fn new(client: EdcConnectorClient) -> Self {
+        EdrCache::builder(client).build()
+    }
+
+    pub(crate) fn client(&self) -> &EdcConnectorClient {
+        &self.client
+    }
+
+    /// Returns a valid EDR for `key`, fetching or refreshing it when needed.
+    #[instrument(level = "debug", skip_all, fields(key = %key))]
+    pub async fn get(&self, key: &EdrKey) -> EdcResult<CachedEdr> {
+        let cached = self.cached(key);
+
+        match cached {
+            Some(edr) if !edr.expires_within(self.refresh_before) => Ok(edr),
+            Some(edr) => self.refresh_entry(key, &edr).await,
+            None => self.load(key).await,
+        }
+    }
+
+    /// Fetches a new token for `key` regardless of the cached expiry, e.g. after the data plane
+    /// rejected the current one.
+    #[instrument(level = "debug", skip_all, fields(key = %key))]
+    pub async fn refresh(&self, key: &EdrKey) -> EdcResult<CachedEdr> {
+        match self.cached(key) {
+            Some(edr) => self.refresh_entry(key, &edr).await,
+            None => self.load(key).await,
+        }
+    }
+
+    /// Deletes the EDR on the connector and drops every entry pointing to it.
+    #[instrument(level = "debug", skip_all, fields(transfer_process_id = %transfer_process_id))]
+    pub async fn delete(&self, transfer_process_id: &str) -> EdcResult<()> {
+        let result = self.client.edrs().delete(transfer_process_id).await;
+        self.evict_transfer(transfer_process_id);
+        result.or_else(|err| if err.is_not_found() { Ok(()) } else { Err(err) })
+    }
+
+    pub fn evict(&self, key: &EdrKey) {
+        self.lock().remove(key);
+    }
+
+    /// Drops every entry served by `transfer_process_id`, e.g. once the transfer terminated.
+    pub fn evict_transfer(&self, transfer_process_id: &str) {
+        self.lock()
+            .retain(|_, edr| edr.transfer_process_id() != transfer_process_id);
+        // A lock still held by a running refresh stays, so later callers wait for it.
+        self.refreshes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|id, lock| id != transfer_process_id || Arc::strong_count(lock) > 1);
+    }
+
+    /// Checks the state of every cached transfer process and evicts the ones that have
+    /// finished. Returns the number of evicted transfer processes.
+    #[instrument(level = "debug", skip_all)]
+    pub async fn evict_terminated(&self) -> EdcResult<usize> {
+        let mut transfer_process_ids = self
+            .lock()
+            .values()
+            .map(|edr| edr.transfer_process_id.clone())
+            .collect::<Vec<_>>();
+        transfer_process_ids.sort();
+        transfer_process_ids.dedup();
+
+        let mut evicted = 0;
+        for id in transfer_process_ids {
+            if self.is_terminated(&id).await? {
+                self.evict_transfer(&id);
+                evicted += 1;
+            }
+        }
+        Ok(evicted)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+        self.refreshes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, lock| Arc::strong_count(lock) > 1);
+    }
+
+    async fn load(&self, key: &EdrKey) -> EdcResult<CachedEdr> {
+        let transfer_process_id = match key {
+            EdrKey::TransferProcess(id) => id.clone(),
+            EdrKey::Asset {
+                asset_id,
+                provider_id,
+            } => self
+                .client
+                .edrs()
+                .get_latest_entry(asset_id, provider_id)
+                .await?
+                .transfer_process_id()
+                .to_string(),
+        };
+
+        let lock = self.refresh_lock(&transfer_process_id);
+        let _guard = lock.lock().await;
+
+        // Another caller may have loaded the entry while we waited for the lock.
+        if let Some(current) = self.cached(key) {
+            return Ok(current);
+        }
+
+        let data_address = self
+            .client
+            .edrs()
+            .get_data_address_with_opts(&transfer_process_id, true)
+            .await?;
+
+        Ok(self.store(key, CachedEdr::new(transfer_process_id, data_address)))
+    }
+
+    async fn refresh_entry(&self, key: &EdrKey, stale: &CachedEdr) -> EdcResult<CachedEdr> {
+        let transfer_process_id = stale.transfer_process_id();
+        let lock = self.refresh_lock(transfer_process_id);
+        let guard = lock.lock().await;
+
+        // Another caller may have refreshed the entry while we waited for the lock.
+        if let Some(current) = self.cached(key) {
+            if current.authorization() != stale.authorization() {
+                return Ok(current);
+            }
+        }
+
+        if self.is_terminated(transfer_process_id).await? {
+            debug!(
+                transfer_process_id,
+                "transfer process terminated, evicting EDR"
+            );
+            self.evict_transfer(transfer_process_id);
+            // The latest EDR of the asset may still point to this transfer process.
+            drop(guard);
+            return match key {
+                EdrKey::Asset { .. } => self.load(key).await,
+                EdrKey::TransferProcess(_) => Err(self.client.edrs().not_found(format!(
+                    "Transfer process {} is terminated",
+                    transfer_process_id
+                ))),
+            };
+        }
+
+        match self.client.edrs().refresh(transfer_process_id).await {
+            Ok(data_address) => Ok(self.store(
+                key,
+                CachedEdr::new(transfer_process_id.to_string(), data_address),
+            )),
+            Err(err) if err.is_not_found() => {
+                self.evict_transfer(transfer_process_id);
+                Err(err)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn is_terminated(&self, transfer_process_id: &str) -> EdcResult<bool> {
+        match self
+            .client
+            .transfer_processes()
+            .get_state(transfer_process_id)
+            .await
+        {
+            Ok(state) => Ok(state.is_finished()),
+            Err(err) if err.is_not_found() => Ok(true),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn refresh_lock(&self, transfer_process_id: &str) -> Arc<AsyncMutex<()>> {
+        self.refreshes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(transfer_process_id.to_string())
+            .or_default()
+            .clone()
+    }
+
+    fn cached(&self, key: &EdrKey) -> Option<CachedEdr> {
+        self.lock().get(key).cloned()
+    }
+
+    fn store(&self, key: &EdrKey, edr: CachedEdr) -> CachedEdr {
+        self.lock().insert(key.clone(), edr.clone());
+        edr
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<EdrKey, CachedEdr>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for EdrCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EdrCache")
+            .field("refresh_before", &self.refresh_before)
+            .field("entries", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,6 +1,11 @@
-use reqwest::StatusCode;
+use reqwest::{Method, StatusCode};
 use serde::Deserialize;
 
+use crate::{
+    data_plane::DataPlaneError, transport::HttpResponse, wait::WaitError, workflow::WorkflowError,
+    EdcConnectorApiVersion,
+};
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
@@ -9,15 +14,186 @@ pub enum Error {
     #[error(transparent)]
     ManagementApi(ManagementApiError),
 
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error(transparent)]
+    Wait(#[from] WaitError),
+
+    #[error(transparent)]
+    Workflow(#[from] WorkflowError),
+
+    #[error(transparent)]
+    Builder(#[from] BuilderError),
+
+    #[error(transparent)]
+    VersionDetection(#[from] VersionDetectionError),
+
+    #[error(transparent)]
+    DataPlane(#[from] DataPlaneError),
+
     #[error(transparent)]
     Auth(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    ServerError,
+    Transport,
+    Other,
+}
+
+impl ErrorKind {
+    pub(crate) fn from_status(status: StatusCode) -> ErrorKind {
+        match status {
+            StatusCode::BAD_REQUEST => ErrorKind::BadRequest,
+            StatusCode::UNAUTHORIZED => ErrorKind::Unauthorized,
+            StatusCode::FORBIDDEN => ErrorKind::Forbidden,
+            StatusCode::NOT_FOUND => ErrorKind::NotFound,
+            StatusCode::CONFLICT => ErrorKind::Conflict,
+            status if status.is_server_error() => ErrorKind::ServerError,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::ManagementApi(err) => err.kind(),
+            Error::Reqwest(_) | Error::Transport(_) => ErrorKind::Transport,
+            Error::Workflow(WorkflowError::Step { source, .. }) => source.kind(),
+            Error::DataPlane(DataPlaneError::Status { status, .. }) => {
+                ErrorKind::from_status(*status)
+            }
+            _ => ErrorKind::Other,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.kind() == ErrorKind::NotFound
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.kind() == ErrorKind::Conflict
+    }
+
+    pub fn is_bad_request(&self) -> bool {
+        self.kind() == ErrorKind::BadRequest
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.kind() == ErrorKind::Unauthorized
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        self.kind() == ErrorKind::Forbidden
+    }
+
+    pub fn is_server_error(&self) -> bool {
+        self.kind() == ErrorKind::ServerError
+    }
+
+    pub fn management_api_error(&self) -> Option<&ManagementApiError> {
+        match self {
+            Error::ManagementApi(err) => Some(err),
+            Error::Workflow(WorkflowError::Step { source, .. }) => source.management_api_error(),
+            _ => None,
+        }
+    }
+
+    /// The HTTP method and URL of the management API call that failed, when known.
+    pub fn endpoint(&self) -> Option<(&Method, &str)> {
+        match self {
+            Error::ManagementApi(err) => Some((err.method(), err.url())),
+            Error::Workflow(WorkflowError::Step { source, .. }) => source.endpoint(),
+            _ => None,
+        }
+    }
+
+    /// The URL of the call that failed, also for requests that never got a response.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Error::ManagementApi(err) => Some(err.url()),
+            Error::Reqwest(err) => err.url().map(|url| url.as_str()),
+            Error::Workflow(WorkflowError::Step { source, .. }) => source.url(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Error::Reqwest(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+            Error::Transport(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returned when none of the management API versions known to the client answered the probe.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "No supported management API version found at {management_url} (tried {})",
+    attempts.iter().map(|(version, reason)| format!("{}: {}", version.as_str(), reason)).collect::<Vec<_>>().join(", ")
+)]
+pub struct VersionDetectionError {
+    pub management_url: String,
+    pub attempts: Vec<(EdcConnectorApiVersion, String)>,
+}
+
 #[derive(Debug, thiserror::Error)]
-#[error("Connector management api returned {status_code}")]
+#[error("Connector management api returned {status_code} for {method} {url}")]
 pub struct ManagementApiError {
     pub status_code: StatusCode,
     pub error_detail: ManagementApiErrorDetailKind,
+    pub(crate) method: Method,
+    pub(crate) url: String,
+}
+
+impl ManagementApiError {
+    pub(crate) fn from_response(method: Method, url: &str, response: HttpResponse) -> Self {
+        let text = String::from_utf8_lossy(&response.body).into_owned();
+
+        let error_detail = match serde_json::from_str::<Vec<ManagementApiErrorDetail>>(&text) {
+            Ok(parsed) => ManagementApiErrorDetailKind::Parsed(parsed),
+            Err(_) => ManagementApiErrorDetailKind::Raw(text),
+        };
+
+        ManagementApiError {
+            status_code: response.status,
+            error_detail,
+            method,
+            url: url.to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        ErrorKind::from_status(self.status_code)
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The parsed error details returned by the connector, empty when the body could not be parsed.
+    pub fn details(&self) -> &[ManagementApiErrorDetail] {
+        match &self.error_detail {
+            ManagementApiErrorDetailKind::Parsed(details) => details,
+            ManagementApiErrorDetailKind::Raw(_) => &[],
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -37,12 +213,20 @@ pub enum ManagementApiErrorDetailKind {
 pub enum BuilderError {
     #[error("Missing mandatory property {0}")]
     MissingProperty(String),
+    #[error("Invalid property {0}: {1}")]
+    InvalidProperty(String, String),
+    #[error("Failed to build the http client: {0}")]
+    HttpClient(reqwest::Error),
 }
 
 impl BuilderError {
     pub fn missing_property(property: &str) -> BuilderError {
         BuilderError::MissingProperty(property.to_string())
     }
+
+    pub fn invalid_property(property: &str, reason: impl Into<String>) -> BuilderError {
+        BuilderError::InvalidProperty(property.to_string(), reason.into())
+    }
 }
 
 #[derive(Debug, thiserror::Error, PartialEq)]
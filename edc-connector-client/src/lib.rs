@@ -32,16 +32,32 @@
 
 pub mod api;
 mod auth;
+#[cfg(feature = "callbacks")]
+pub mod callbacks;
 mod client;
+mod config;
+pub mod data_plane;
+mod edr_cache;
 mod error;
+mod redact;
+mod retry;
+pub mod transport;
+mod wait;
+pub mod workflow;
 
 pub mod types;
-pub use auth::{Auth, OAuth2Config};
-pub use client::{EdcConnectorApiVersion, EdcConnectorClient};
+pub use auth::{
+    AssertionAlgorithm, Auth, AuthProvider, Basic, Bearer, ClientAssertion, OAuth2Config,
+};
+pub use client::{EdcClientConnectorBuilder, EdcConnectorApiVersion, EdcConnectorClient};
+pub use config::{AuthConfig, ConfigError, EdcClientConfig, RetryConfig};
+pub use edr_cache::{CachedEdr, EdrCache, EdrKey};
 pub use error::{
-    BuilderError, ConversionError, Error, ManagementApiError, ManagementApiErrorDetail,
-    ManagementApiErrorDetailKind,
+    BuilderError, ConversionError, Error, ErrorKind, ManagementApiError, ManagementApiErrorDetail,
+    ManagementApiErrorDetailKind, VersionDetectionError,
 };
+pub use retry::{RetryEvent, RetryHook, RetryPolicy, RetryReason};
+pub use wait::{WaitError, WaitOptions};
 
 pub const EDC_NAMESPACE: &str = "https://w3id.org/edc/v0.0.1/ns/";
 pub const DATASPACE_PROTOCOL: &str = "dataspace-protocol-http";
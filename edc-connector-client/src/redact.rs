@@ -0,0 +1,70 @@
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use serde_json::Value;
+
+const REDACTED: &str = "***";
+
+const SENSITIVE_KEYS: &[&str] = &[
+    "authorization",
+    "authKey",
+    "refreshToken",
+    "privateEntries",
+    "clientSecret",
+    "client_secret",
+];
+
+const SENSITIVE_HEADERS: &[&str] = &["x-api-key"];
+
+pub(crate) fn headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if name == AUTHORIZATION || SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+pub(crate) fn body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(value) => json(&value),
+        Err(_) => format!("<{} bytes>", body.len()),
+    }
+}
+
+pub(crate) fn json(value: &Value) -> String {
+    let mut value = value.clone();
+    redact(&mut value);
+    value.to_string()
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let is_secret = map
+                .get("@type")
+                .and_then(Value::as_str)
+                .is_some_and(|ty| local_name(ty) == "Secret");
+
+            for (key, value) in map.iter_mut() {
+                let name = local_name(key);
+                if SENSITIVE_KEYS.contains(&name) || (is_secret && name == "value") {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Strips the namespace from expanded JSON-LD keys such as
+/// `https://w3id.org/edc/v0.0.1/ns/authorization` or `edc:authorization`.
+fn local_name(key: &str) -> &str {
+    key.rsplit(['/', ':']).next().unwrap_or(key)
+}
@@ -0,0 +1,126 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use bon::Builder;
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+
+pub type RetryHook = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// Controls how requests to the management API are retried on transient failures.
+///
+/// The default policy performs a single attempt and never retries.
+#[derive(Clone, Builder)]
+pub struct RetryPolicy {
+    #[builder(default = 3)]
+    max_attempts: u32,
+    #[builder(default = Duration::from_millis(200))]
+    initial_backoff: Duration,
+    #[builder(default = Duration::from_secs(5))]
+    max_backoff: Duration,
+    #[builder(default = 2.0)]
+    multiplier: f64,
+    #[builder(default = true)]
+    jitter: bool,
+    #[builder(default = default_retryable_status_codes())]
+    retryable_status_codes: Vec<StatusCode>,
+    #[builder(default)]
+    retry_non_idempotent: bool,
+    #[builder(with = |hook: impl Fn(&RetryEvent) + Send + Sync + 'static| Arc::new(hook) as RetryHook)]
+    on_retry: Option<RetryHook>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryEvent {
+    pub method: Method,
+    pub url: String,
+    pub attempt: u32,
+    pub delay: Duration,
+    pub reason: RetryReason,
+}
+
+#[derive(Debug, Clone)]
+pub enum RetryReason {
+    Status(StatusCode),
+    Transport(String),
+}
+
+impl fmt::Display for RetryReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetryReason::Status(status) => write!(f, "status {}", status),
+            RetryReason::Transport(err) => write!(f, "transport error: {}", err),
+        }
+    }
+}
+
+fn default_retryable_status_codes() -> Vec<StatusCode> {
+    vec![
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::BAD_GATEWAY,
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::GATEWAY_TIMEOUT,
+    ]
+}
+
+impl RetryPolicy {
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::builder().max_attempts(1).build()
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn retry_non_idempotent(&self) -> bool {
+        self.retry_non_idempotent
+    }
+
+    pub(crate) fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retryable_status_codes.contains(&status)
+    }
+
+    pub(crate) fn allows(&self, attempt: u32, idempotent: bool) -> bool {
+        attempt < self.max_attempts && (idempotent || self.retry_non_idempotent)
+    }
+
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        let delay = self
+            .initial_backoff
+            .mul_f64(exp.max(1.0))
+            .min(self.max_backoff);
+
+        if self.jitter && !delay.is_zero() {
+            let half = delay / 2;
+            half + rand::rng().random_range(Duration::ZERO..=half)
+        } else {
+            delay
+        }
+    }
+
+    pub(crate) fn notify(&self, event: &RetryEvent) {
+        if let Some(hook) = &self.on_retry {
+            hook(event)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("retryable_status_codes", &self.retryable_status_codes)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,69 @@
+use futures::future::BoxFuture;
+use reqwest::{header::HeaderMap, Client, Method, StatusCode};
+use serde_json::Value;
+
+use crate::EdcResult;
+
+/// A request to the management API, with the JSON body already serialized.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// The HTTP layer used by [`EdcConnectorClient`](crate::EdcConnectorClient) to talk to the
+/// management API.
+///
+/// The default implementation is [`ReqwestTransport`]. Custom implementations can be used to run
+/// the client against in-memory fakes, recorders or other HTTP stacks.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, EdcResult<HttpResponse>>;
+}
+
+#[derive(Clone, Default)]
+pub struct ReqwestTransport(Client);
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self(client)
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.0
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, EdcResult<HttpResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .0
+                .request(request.method, request.url)
+                .headers(request.headers);
+
+            if let Some(body) = &request.body {
+                builder = builder.json(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
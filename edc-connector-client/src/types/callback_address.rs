@@ -27,6 +27,30 @@ pub struct CallbackAddressAuth {
     auth_code_id: String,
 }
 
+impl CallbackAddress {
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn events(&self) -> &[String] {
+        &self.events
+    }
+
+    pub fn auth(&self) -> Option<&CallbackAddressAuth> {
+        self.auth.as_ref()
+    }
+}
+
+impl CallbackAddressAuth {
+    pub fn auth_key(&self) -> &str {
+        &self.auth_key
+    }
+
+    pub fn auth_code_id(&self) -> &str {
+        &self.auth_code_id
+    }
+}
+
 impl<S: callback_address_builder::State> CallbackAddressBuilder<S> {
     pub fn auth(mut self, auth_key: &str, auth_code_id: &str) -> Self {
         self.auth = Some(CallbackAddressAuth {
@@ -28,6 +28,13 @@ pub struct ContractRequest {
     ty: String,
 }
 
+impl ContractRequest {
+    #[cfg(feature = "callbacks")]
+    pub(crate) fn add_callback_address(&mut self, callback_address: CallbackAddress) {
+        self.callback_addresses.push(callback_address);
+    }
+}
+
 impl<S: contract_request_builder::State> ContractRequestBuilder<S> {
     pub fn callback_address(mut self, callback_address: CallbackAddress) -> Self {
         self.callback_addresses.push(callback_address);
@@ -98,6 +105,42 @@ pub struct TerminateNegotiation {
     pub(crate) reason: String,
 }
 
+impl ContractNegotiationState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContractNegotiationState::Initial => "INITIAL",
+            ContractNegotiationState::Requesting => "REQUESTING",
+            ContractNegotiationState::Requested => "REQUESTED",
+            ContractNegotiationState::Offering => "OFFERING",
+            ContractNegotiationState::Offered => "OFFERED",
+            ContractNegotiationState::Accepting => "ACCEPTING",
+            ContractNegotiationState::Accepted => "ACCEPTED",
+            ContractNegotiationState::Agreeing => "AGREEING",
+            ContractNegotiationState::Agreed => "AGREED",
+            ContractNegotiationState::Verifying => "VERIFYING",
+            ContractNegotiationState::Verified => "VERIFIED",
+            ContractNegotiationState::Finalizing => "FINALIZING",
+            ContractNegotiationState::Finalized => "FINALIZED",
+            ContractNegotiationState::Terminating => "TERMINATING",
+            ContractNegotiationState::Terminated => "TERMINATED",
+            ContractNegotiationState::Other(state) => state,
+        }
+    }
+
+    /// Whether the negotiation can no longer leave this state.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ContractNegotiationState::Finalized | ContractNegotiationState::Terminated
+        )
+    }
+
+    /// Whether `target` can still be reached from this state.
+    pub fn can_reach(&self, _target: &ContractNegotiationState) -> bool {
+        !self.is_terminal()
+    }
+}
+
 impl NegotiationState {
     pub fn state(&self) -> &ContractNegotiationState {
         &self.state
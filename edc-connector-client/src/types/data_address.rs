@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{error::BuilderError, ConversionError};
+use crate::{error::BuilderError, ConversionError, EDC_NAMESPACE};
 
 use super::properties::{FromValue, Properties, ToValue};
 
@@ -23,6 +26,65 @@ impl DataAddress {
     {
         self.properties.get(property)
     }
+
+    pub fn kind(&self) -> Option<&str> {
+        self.str_property("type")
+    }
+
+    /// The endpoint of an EDR data address.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.str_property("endpoint")
+    }
+
+    pub fn endpoint_type(&self) -> Option<&str> {
+        self.str_property("endpointType")
+    }
+
+    /// The token to send to the EDR endpoint.
+    pub fn authorization(&self) -> Option<&str> {
+        self.str_property("authorization")
+    }
+
+    pub fn auth_type(&self) -> Option<&str> {
+        self.str_property("authType")
+    }
+
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.str_property("refreshToken")
+    }
+
+    pub fn refresh_endpoint(&self) -> Option<&str> {
+        self.str_property("refreshEndpoint")
+    }
+
+    pub fn refresh_audience(&self) -> Option<&str> {
+        self.str_property("refreshAudience")
+    }
+
+    /// The lifetime of the EDR token, sent by the connector either as a number or as a string
+    /// of seconds.
+    pub fn expires_in(&self) -> Option<Duration> {
+        match self.raw_property("expiresIn")? {
+            Value::Number(seconds) => seconds.as_u64(),
+            Value::String(seconds) => seconds.parse().ok(),
+            _ => None,
+        }
+        .map(Duration::from_secs)
+    }
+
+    fn raw_property(&self, property: &str) -> Option<&Value> {
+        self.properties
+            .get_raw(property)
+            .or_else(|| {
+                self.properties
+                    .get_raw(&format!("{}{}", EDC_NAMESPACE, property))
+            })
+            .map(|value| &value.0)
+    }
+
+    fn str_property(&self, property: &str) -> Option<&str> {
+        self.raw_property(property).and_then(Value::as_str)
+    }
 }
 
 #[derive(Default)]
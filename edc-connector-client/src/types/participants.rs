@@ -1,7 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use bon::Builder;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+use crate::ConversionError;
+
+use super::properties::{FromValue, Properties, ToValue};
 
 #[derive(Builder, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -17,13 +21,113 @@ pub struct NewParticipantContext {
     ty: String,
 }
 
-#[derive(Builder, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantContext {
+    #[builder(field)]
+    #[serde(default)]
+    properties: Properties,
+    #[builder(into)]
+    #[serde(rename = "@id")]
+    id: String,
+    #[builder(into)]
+    identity: String,
+    #[builder(default = "ParticipantContext".to_string())]
+    #[serde(rename = "@type", default = "participant_context_type")]
+    ty: String,
+    #[serde(default, skip_serializing)]
+    state: Option<ParticipantContextState>,
+    #[serde(default, skip_serializing)]
+    created_at: Option<i64>,
+    #[serde(default, skip_serializing)]
+    last_modified: Option<i64>,
+}
+
+fn participant_context_type() -> String {
+    "ParticipantContext".to_string()
+}
+
+impl ParticipantContext {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    pub fn state(&self) -> Option<&ParticipantContextState> {
+        self.state.as_ref()
+    }
+
+    pub fn created_at(&self) -> Option<i64> {
+        self.created_at
+    }
+
+    pub fn last_modified(&self) -> Option<i64> {
+        self.last_modified
+    }
+
+    pub fn property<T>(&self, property: &str) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        self.properties.get(property)
+    }
+}
+
+impl<S: participant_context_builder::State> ParticipantContextBuilder<S> {
+    pub fn property<T>(mut self, property: &str, value: T) -> Self
+    where
+        T: ToValue,
+    {
+        self.properties.set(property, value);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ParticipantContextState {
+    Created,
+    Activated,
+    Deactivated,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Clone, Builder, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticipantContextConfig {
     #[builder(default = "ParticipantContextConfig".to_string())]
-    #[serde(rename = "@type")]
+    #[serde(rename = "@type", default = "participant_context_config_type")]
     ty: String,
+    #[serde(default)]
     entries: HashMap<String, String>,
     #[builder(default)]
+    #[serde(default)]
     private_entries: HashMap<String, String>,
 }
+
+fn participant_context_config_type() -> String {
+    "ParticipantContextConfig".to_string()
+}
+
+impl ParticipantContextConfig {
+    pub fn entries(&self) -> &HashMap<String, String> {
+        &self.entries
+    }
+
+    pub fn private_entries(&self) -> &HashMap<String, String> {
+        &self.private_entries
+    }
+}
+
+impl fmt::Debug for ParticipantContextConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParticipantContextConfig")
+            .field("entries", &self.entries)
+            .field("private_entries", &self.private_entries.keys())
+            .finish()
+    }
+}
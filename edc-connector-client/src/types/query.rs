@@ -1,3 +1,4 @@
+use bon::Builder;
 use serde::{Deserialize, Serialize};
 
 use super::properties::{PropertyValue, ToValue};
@@ -149,3 +150,19 @@ impl Default for Query {
         }
     }
 }
+
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Pagination {
+    page_size: Option<u32>,
+    max_items: Option<usize>,
+}
+
+impl Pagination {
+    pub fn page_size(&self) -> Option<u32> {
+        self.page_size
+    }
+
+    pub fn max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+}
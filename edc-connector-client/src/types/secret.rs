@@ -1,7 +1,9 @@
+use std::fmt;
+
 use bon::Builder;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
 pub struct NewSecret {
     #[builder(into)]
@@ -14,7 +16,7 @@ pub struct NewSecret {
     ty: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Serialize, Deserialize, Clone, Builder)]
 #[serde(rename_all = "camelCase")]
 pub struct Secret {
     #[builder(into)]
@@ -27,6 +29,13 @@ pub struct Secret {
     ty: String,
 }
 
+/// Only the id of a secret, so that query results do not keep secret values in memory.
+#[derive(Deserialize)]
+pub(crate) struct SecretId {
+    #[serde(rename = "@id")]
+    pub(crate) id: String,
+}
+
 impl Secret {
     pub fn value(&self) -> &str {
         &self.value
@@ -36,3 +45,21 @@ impl Secret {
         &self.id
     }
 }
+
+impl fmt::Debug for NewSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewSecret")
+            .field("id", &self.id)
+            .field("value", &"***")
+            .finish()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secret")
+            .field("id", &self.id)
+            .field("value", &"***")
+            .finish()
+    }
+}
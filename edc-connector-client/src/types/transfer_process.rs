@@ -31,6 +31,13 @@ pub struct TransferRequest {
     ty: String,
 }
 
+impl TransferRequest {
+    #[cfg(feature = "callbacks")]
+    pub(crate) fn add_callback_address(&mut self, callback_address: CallbackAddress) {
+        self.callback_addresses.push(callback_address);
+    }
+}
+
 impl<S: transfer_request_builder::State> TransferRequestBuilder<S> {
     pub fn callback_address(mut self, callback_address: CallbackAddress) -> Self {
         self.callback_addresses.push(callback_address);
@@ -143,6 +150,67 @@ pub enum TransferProcessState {
     Other(String),
 }
 
+impl TransferProcessState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransferProcessState::Initial => "INITIAL",
+            TransferProcessState::Provisioning => "PROVISIONING",
+            TransferProcessState::ProvisioningRequested => "PROVISIONING_REQUESTED",
+            TransferProcessState::Provisioned => "PROVISIONED",
+            TransferProcessState::Requesting => "REQUESTING",
+            TransferProcessState::Requested => "REQUESTED",
+            TransferProcessState::Starting => "STARTING",
+            TransferProcessState::Started => "STARTED",
+            TransferProcessState::Suspending => "SUSPENDING",
+            TransferProcessState::Suspended => "SUSPENDED",
+            TransferProcessState::Resuming => "RESUMING",
+            TransferProcessState::Resumed => "RESUMED",
+            TransferProcessState::Completing => "COMPLETING",
+            TransferProcessState::Completed => "COMPLETED",
+            TransferProcessState::Terminating => "TERMINATING",
+            TransferProcessState::Terminated => "TERMINATED",
+            TransferProcessState::Deprovisioning => "DEPROVISIONING",
+            TransferProcessState::DeprovisioningRequested => "DEPROVISIONING_REQUESTED",
+            TransferProcessState::Deprovisioned => "DEPROVISIONED",
+            TransferProcessState::Other(state) => state,
+        }
+    }
+
+    /// Whether the transfer process can no longer leave this state.
+    ///
+    /// Completed and terminated transfer processes still move on to deprovisioning.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TransferProcessState::Deprovisioned)
+    }
+
+    /// Whether the transfer process stopped moving data, i.e. it completed, was terminated or is
+    /// being deprovisioned.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            TransferProcessState::Completed
+                | TransferProcessState::Terminated
+                | TransferProcessState::Deprovisioning
+                | TransferProcessState::DeprovisioningRequested
+                | TransferProcessState::Deprovisioned
+        )
+    }
+
+    /// Whether `target` can still be reached from this state.
+    pub fn can_reach(&self, target: &TransferProcessState) -> bool {
+        if self.is_finished() {
+            matches!(
+                target,
+                TransferProcessState::Deprovisioning
+                    | TransferProcessState::DeprovisioningRequested
+                    | TransferProcessState::Deprovisioned
+            ) && !self.is_terminal()
+        } else {
+            true
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransferProcessKind {
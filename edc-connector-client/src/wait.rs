@@ -0,0 +1,152 @@
+use std::{future::Future, time::Duration};
+
+use bon::Builder;
+use tokio::time::{sleep, Instant};
+
+use crate::{
+    types::{
+        contract_negotiation::ContractNegotiationState, transfer_process::TransferProcessState,
+    },
+    EdcResult, Error,
+};
+
+/// Controls how the state waiters poll the connector.
+#[derive(Debug, Clone, Builder)]
+pub struct WaitOptions {
+    #[builder(default = Duration::from_secs(30))]
+    timeout: Duration,
+    #[builder(default = Duration::from_millis(200))]
+    poll_interval: Duration,
+    #[builder(default = 1.0)]
+    backoff: f64,
+    #[builder(default = Duration::from_secs(5))]
+    max_poll_interval: Duration,
+}
+
+impl WaitOptions {
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    pub fn backoff(&self) -> f64 {
+        self.backoff
+    }
+
+    pub fn max_poll_interval(&self) -> Duration {
+        self.max_poll_interval
+    }
+
+    fn next_interval(&self, interval: Duration) -> Duration {
+        interval
+            .mul_f64(self.backoff.max(1.0))
+            .min(self.max_poll_interval)
+    }
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        WaitOptions::builder().build()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WaitError {
+    #[error("Timed out waiting for {id} to reach {target} (last state: {})", last_state.as_deref().unwrap_or("unknown"))]
+    Timeout {
+        id: String,
+        target: String,
+        last_state: Option<String>,
+    },
+    #[error("{id} reached state {state}, from which {target} can no longer be reached")]
+    TerminalState {
+        id: String,
+        target: String,
+        state: String,
+    },
+}
+
+pub(crate) trait ProcessState: PartialEq {
+    fn name(&self) -> &str;
+
+    fn can_reach(&self, target: &Self) -> bool;
+}
+
+impl ProcessState for ContractNegotiationState {
+    fn name(&self) -> &str {
+        self.as_str()
+    }
+
+    fn can_reach(&self, target: &Self) -> bool {
+        self.can_reach(target)
+    }
+}
+
+impl ProcessState for TransferProcessState {
+    fn name(&self) -> &str {
+        self.as_str()
+    }
+
+    fn can_reach(&self, target: &Self) -> bool {
+        self.can_reach(target)
+    }
+}
+
+pub(crate) async fn wait_for_state<T, S, F, Fut>(
+    id: &str,
+    target: &S,
+    options: &WaitOptions,
+    fetch: F,
+    state: impl Fn(&T) -> &S,
+) -> EdcResult<T>
+where
+    S: ProcessState,
+    F: Fn() -> Fut,
+    Fut: Future<Output = EdcResult<T>>,
+{
+    let deadline = Instant::now() + options.timeout;
+    let mut interval = options.poll_interval;
+    let mut last_state = None;
+
+    loop {
+        let entity = fetch().await?;
+        let current = state(&entity);
+
+        if current == target {
+            return Ok(entity);
+        }
+
+        if !current.can_reach(target) {
+            return Err(Error::Wait(WaitError::TerminalState {
+                id: id.to_string(),
+                target: target.name().to_string(),
+                state: current.name().to_string(),
+            }));
+        }
+
+        if last_state.as_deref() != Some(current.name()) {
+            tracing::debug!(
+                id,
+                state = current.name(),
+                target = target.name(),
+                "waiting for state"
+            );
+            last_state = Some(current.name().to_string());
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(Error::Wait(WaitError::Timeout {
+                id: id.to_string(),
+                target: target.name().to_string(),
+                last_state,
+            }));
+        }
+
+        sleep(interval.min(deadline - now)).await;
+        interval = options.next_interval(interval);
+    }
+}
@@ -0,0 +1,100 @@
+mod consumer;
+mod provider;
+
+use std::fmt;
+
+use crate::Error;
+
+pub use consumer::{ConsumeOutcome, ConsumeRequest, ConsumerWorkflow, OfferSelector};
+pub use provider::{ProviderWorkflow, PublishRequest, PublishedOffering};
+
+/// A single step of a multi-call workflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WorkflowStep {
+    FetchDataset,
+    SelectOffer,
+    InitiateNegotiation,
+    AwaitAgreement,
+    FetchAgreement,
+    InitiateTransfer,
+    AwaitTransfer,
+    FetchDataAddress,
+    CreateAsset,
+    CreateAccessPolicy,
+    CreateContractPolicy,
+    CreateContractDefinition,
+    DeleteContractDefinition,
+    DeleteContractPolicy,
+    DeleteAccessPolicy,
+    DeleteAsset,
+}
+
+impl WorkflowStep {
+    pub fn as_str(&self) -> &str {
+        match self {
+            WorkflowStep::FetchDataset => "fetch dataset",
+            WorkflowStep::SelectOffer => "select offer",
+            WorkflowStep::InitiateNegotiation => "initiate negotiation",
+            WorkflowStep::AwaitAgreement => "await agreement",
+            WorkflowStep::FetchAgreement => "fetch agreement",
+            WorkflowStep::InitiateTransfer => "initiate transfer",
+            WorkflowStep::AwaitTransfer => "await transfer",
+            WorkflowStep::FetchDataAddress => "fetch data address",
+            WorkflowStep::CreateAsset => "create asset",
+            WorkflowStep::CreateAccessPolicy => "create access policy",
+            WorkflowStep::CreateContractPolicy => "create contract policy",
+            WorkflowStep::CreateContractDefinition => "create contract definition",
+            WorkflowStep::DeleteContractDefinition => "delete contract definition",
+            WorkflowStep::DeleteContractPolicy => "delete contract policy",
+            WorkflowStep::DeleteAccessPolicy => "delete access policy",
+            WorkflowStep::DeleteAsset => "delete asset",
+        }
+    }
+}
+
+impl fmt::Display for WorkflowStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkflowError {
+    #[error("Workflow step '{step}' failed: {source}")]
+    Step {
+        step: WorkflowStep,
+        #[source]
+        source: Box<Error>,
+    },
+    #[error("No offer of dataset {dataset} matched the offer selector")]
+    NoMatchingOffer { dataset: String },
+    #[error("Negotiation {negotiation} finalized without a contract agreement")]
+    MissingAgreement { negotiation: String },
+}
+
+impl WorkflowError {
+    /// The step of the workflow that failed.
+    pub fn step(&self) -> WorkflowStep {
+        match self {
+            WorkflowError::Step { step, .. } => *step,
+            WorkflowError::NoMatchingOffer { .. } => WorkflowStep::SelectOffer,
+            WorkflowError::MissingAgreement { .. } => WorkflowStep::AwaitAgreement,
+        }
+    }
+}
+
+pub(crate) trait StepExt<T> {
+    fn step(self, step: WorkflowStep) -> Result<T, Error>;
+}
+
+impl<T> StepExt<T> for Result<T, Error> {
+    fn step(self, step: WorkflowStep) -> Result<T, Error> {
+        self.map_err(|source| {
+            Error::Workflow(WorkflowError::Step {
+                step,
+                source: Box::new(source),
+            })
+        })
+    }
+}
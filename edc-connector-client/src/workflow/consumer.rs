@@ -0,0 +1,236 @@
+use std::{fmt, sync::Arc};
+
+use bon::Builder;
+use tracing::instrument;
+
+use crate::{
+    types::{
+        catalog::DatasetRequest,
+        contract_agreement::ContractAgreement,
+        contract_negotiation::{ContractNegotiationState, ContractRequest},
+        data_address::DataAddress,
+        policy::{Policy, PolicyKind, Target},
+        transfer_process::{TransferProcess, TransferProcessState, TransferRequest},
+        Protocol,
+    },
+    EdcConnectorClient, EdcResult, WaitOptions,
+};
+
+use super::{StepExt, WorkflowError, WorkflowStep};
+
+pub type OfferSelector = Arc<dyn Fn(&Policy) -> bool + Send + Sync>;
+
+/// Describes the asset to consume and how to negotiate and transfer it.
+#[derive(Clone, Builder)]
+pub struct ConsumeRequest {
+    #[builder(into)]
+    asset_id: String,
+    #[builder(into)]
+    counter_party_address: String,
+    #[builder(into)]
+    counter_party_id: String,
+    #[builder(into, default)]
+    protocol: Protocol,
+    #[builder(into, default = "HttpData-PULL".to_string())]
+    transfer_type: String,
+    destination: Option<DataAddress>,
+    #[builder(with = |selector: impl Fn(&Policy) -> bool + Send + Sync + 'static| Arc::new(selector) as OfferSelector)]
+    offer_selector: Option<OfferSelector>,
+    #[builder(default)]
+    negotiation_wait: WaitOptions,
+    #[builder(default)]
+    transfer_wait: WaitOptions,
+}
+
+impl fmt::Debug for ConsumeRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsumeRequest")
+            .field("asset_id", &self.asset_id)
+            .field("counter_party_address", &self.counter_party_address)
+            .field("counter_party_id", &self.counter_party_id)
+            .field("protocol", &self.protocol)
+            .field("transfer_type", &self.transfer_type)
+            .field("destination", &self.destination)
+            .field("negotiation_wait", &self.negotiation_wait)
+            .field("transfer_wait", &self.transfer_wait)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsumeOutcome {
+    agreement: ContractAgreement,
+    transfer_process: TransferProcess,
+    data_address: DataAddress,
+}
+
+impl ConsumeOutcome {
+    pub fn agreement(&self) -> &ContractAgreement {
+        &self.agreement
+    }
+
+    pub fn transfer_process(&self) -> &TransferProcess {
+        &self.transfer_process
+    }
+
+    pub fn data_address(&self) -> &DataAddress {
+        &self.data_address
+    }
+
+    pub fn into_parts(self) -> (ContractAgreement, TransferProcess, DataAddress) {
+        (self.agreement, self.transfer_process, self.data_address)
+    }
+}
+
+pub struct ConsumerWorkflow<'a>(&'a EdcConnectorClient);
+
+impl<'a> ConsumerWorkflow<'a> {
+    pub(crate) fn new(client: &'a EdcConnectorClient) -> ConsumerWorkflow<'a> {
+        ConsumerWorkflow(client)
+    }
+
+    /// Negotiates a contract for an asset, starts a transfer with the resulting agreement and
+    /// returns the EDR data address once the transfer is started.
+    ///
+    /// Failures are reported as [`Error::Workflow`](crate::Error::Workflow) carrying the step
+    /// that failed.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(workflow = "consumer", operation = "consume", asset_id = %request.asset_id)
+    )]
+    pub async fn consume(&self, request: &ConsumeRequest) -> EdcResult<ConsumeOutcome> {
+        let dataset_request = DatasetRequest::builder()
+            .id(&request.asset_id)
+            .counter_party_address(&request.counter_party_address)
+            .counter_party_id(&request.counter_party_id)
+            .protocol(request.protocol.clone())
+            .build();
+
+        let dataset = self
+            .0
+            .catalogue()
+            .dataset(&dataset_request)
+            .await
+            .step(WorkflowStep::FetchDataset)?;
+
+        let offer = dataset
+            .offers()
+            .iter()
+            .find(|offer| {
+                request
+                    .offer_selector
+                    .as_ref()
+                    .is_none_or(|selector| selector(offer))
+            })
+            .ok_or_else(|| WorkflowError::NoMatchingOffer {
+                dataset: dataset.id().to_string(),
+            })?;
+
+        let contract_request = ContractRequest::builder()
+            .counter_party_address(&request.counter_party_address)
+            .counter_party_id(&request.counter_party_id)
+            .protocol(request.protocol.clone())
+            .policy(offer_for(offer, request))
+            .build();
+
+        let negotiation_id = self
+            .0
+            .contract_negotiations()
+            .initiate(&contract_request)
+            .await
+            .step(WorkflowStep::InitiateNegotiation)?
+            .id()
+            .to_string();
+
+        let negotiation = self
+            .0
+            .contract_negotiations()
+            .wait_for_state(
+                &negotiation_id,
+                ContractNegotiationState::Finalized,
+                request.negotiation_wait.clone(),
+            )
+            .await
+            .step(WorkflowStep::AwaitAgreement)?;
+
+        let agreement_id = negotiation.contract_agreement_id().cloned().ok_or(
+            WorkflowError::MissingAgreement {
+                negotiation: negotiation_id,
+            },
+        )?;
+
+        let agreement = self
+            .0
+            .contract_agreements()
+            .get(&agreement_id)
+            .await
+            .step(WorkflowStep::FetchAgreement)?;
+
+        let mut transfer_request = TransferRequest::builder()
+            .counter_party_address(&request.counter_party_address)
+            .contract_id(&agreement_id)
+            .protocol(request.protocol.clone())
+            .transfer_type(&request.transfer_type);
+
+        if let Some(destination) = &request.destination {
+            transfer_request = transfer_request.destination(destination.clone());
+        }
+
+        let transfer_id = self
+            .0
+            .transfer_processes()
+            .initiate(&transfer_request.build())
+            .await
+            .step(WorkflowStep::InitiateTransfer)?
+            .id()
+            .to_string();
+
+        let transfer_process = self
+            .0
+            .transfer_processes()
+            .wait_for_state(
+                &transfer_id,
+                TransferProcessState::Started,
+                request.transfer_wait.clone(),
+            )
+            .await
+            .step(WorkflowStep::AwaitTransfer)?;
+
+        let data_address = self
+            .0
+            .edrs()
+            .get_data_address(&transfer_id)
+            .await
+            .step(WorkflowStep::FetchDataAddress)?;
+
+        Ok(ConsumeOutcome {
+            agreement,
+            transfer_process,
+            data_address,
+        })
+    }
+}
+
+fn offer_for(offer: &Policy, request: &ConsumeRequest) -> Policy {
+    Policy::builder()
+        .maybe_id(offer.id().cloned())
+        .kind(PolicyKind::Offer)
+        .assigner(
+            offer
+                .assigner()
+                .cloned()
+                .unwrap_or_else(|| request.counter_party_id.clone()),
+        )
+        .maybe_assignee(offer.assignee().cloned())
+        .target(
+            offer
+                .target()
+                .cloned()
+                .unwrap_or_else(|| Target::simple(&request.asset_id)),
+        )
+        .permissions(offer.permissions().to_vec())
+        .prohibitions(offer.prohibitions().to_vec())
+        .obligations(offer.obligations().to_vec())
+        .build()
+}
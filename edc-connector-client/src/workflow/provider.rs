@@ -0,0 +1,246 @@
+use bon::Builder;
+use tracing::{instrument, warn};
+
+use crate::{
+    types::{
+        asset::NewAsset, contract_definition::NewContractDefinition, policy::NewPolicyDefinition,
+        policy::Policy, query::Criterion,
+    },
+    BuilderError, EdcConnectorClient, EdcResult, EDC_NAMESPACE,
+};
+
+use super::{StepExt, WorkflowStep};
+
+/// Describes an offering: the asset, its access and contract policies and the contract
+/// definition that binds them together.
+#[derive(Debug, Builder)]
+pub struct PublishRequest {
+    asset: NewAsset,
+    access_policy: Policy,
+    contract_policy: Policy,
+    #[builder(into)]
+    access_policy_id: Option<String>,
+    #[builder(into)]
+    contract_policy_id: Option<String>,
+    #[builder(into)]
+    contract_definition_id: Option<String>,
+}
+
+impl PublishRequest {
+    fn shares_policy_id(&self) -> bool {
+        self.contract_policy_id.is_some() && self.contract_policy_id == self.access_policy_id
+    }
+}
+
+/// The ids of the entities created by [`ProviderWorkflow::publish`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedOffering {
+    asset_id: String,
+    access_policy_id: String,
+    contract_policy_id: String,
+    contract_definition_id: String,
+}
+
+impl PublishedOffering {
+    pub fn new(
+        asset_id: impl Into<String>,
+        access_policy_id: impl Into<String>,
+        contract_policy_id: impl Into<String>,
+        contract_definition_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            access_policy_id: access_policy_id.into(),
+            contract_policy_id: contract_policy_id.into(),
+            contract_definition_id: contract_definition_id.into(),
+        }
+    }
+
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    pub fn access_policy_id(&self) -> &str {
+        &self.access_policy_id
+    }
+
+    pub fn contract_policy_id(&self) -> &str {
+        &self.contract_policy_id
+    }
+
+    pub fn contract_definition_id(&self) -> &str {
+        &self.contract_definition_id
+    }
+}
+
+pub struct ProviderWorkflow<'a>(&'a EdcConnectorClient);
+
+impl<'a> ProviderWorkflow<'a> {
+    pub(crate) fn new(client: &'a EdcConnectorClient) -> ProviderWorkflow<'a> {
+        ProviderWorkflow(client)
+    }
+
+    /// Creates the asset, the policies and the contract definition of an offering.
+    ///
+    /// If a step fails, the entities already created are deleted before the error is returned.
+    /// When the access and contract policy ids are the same, the policy is created once and used
+    /// for both, and the access and contract policies must then be equal.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(workflow = "provider", operation = "publish")
+    )]
+    pub async fn publish(&self, request: &PublishRequest) -> EdcResult<PublishedOffering> {
+        if request.shares_policy_id() && request.access_policy != request.contract_policy {
+            return Err(BuilderError::invalid_property(
+                "contract_policy",
+                "must equal the access policy when both use the same policy id",
+            )
+            .into());
+        }
+
+        let mut created = Created::default();
+
+        match self.create(request, &mut created).await {
+            Ok(offering) => Ok(offering),
+            Err(err) => {
+                self.rollback(created).await;
+                Err(err)
+            }
+        }
+    }
+
+    /// Deletes the contract definition, the policies and the asset of an offering.
+    ///
+    /// Entities that no longer exist are skipped.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(workflow = "provider", operation = "unpublish", id = %offering.contract_definition_id)
+    )]
+    pub async fn unpublish(&self, offering: &PublishedOffering) -> EdcResult<()> {
+        ignore_not_found(
+            self.0
+                .contract_definitions()
+                .delete(&offering.contract_definition_id)
+                .await,
+        )
+        .step(WorkflowStep::DeleteContractDefinition)?;
+
+        ignore_not_found(self.0.policies().delete(&offering.contract_policy_id).await)
+            .step(WorkflowStep::DeleteContractPolicy)?;
+
+        if offering.access_policy_id != offering.contract_policy_id {
+            ignore_not_found(self.0.policies().delete(&offering.access_policy_id).await)
+                .step(WorkflowStep::DeleteAccessPolicy)?;
+        }
+
+        ignore_not_found(self.0.assets().delete(&offering.asset_id).await)
+            .step(WorkflowStep::DeleteAsset)
+    }
+
+    async fn create(
+        &self,
+        request: &PublishRequest,
+        created: &mut Created,
+    ) -> EdcResult<PublishedOffering> {
+        let asset_id = self
+            .0
+            .assets()
+            .create(&request.asset)
+            .await
+            .step(WorkflowStep::CreateAsset)?
+            .id()
+            .to_string();
+        created.asset_id = Some(asset_id.clone());
+
+        let access_policy = NewPolicyDefinition::builder()
+            .maybe_id(request.access_policy_id.clone())
+            .policy(request.access_policy.clone())
+            .build();
+
+        let access_policy_id = self
+            .0
+            .policies()
+            .create(&access_policy)
+            .await
+            .step(WorkflowStep::CreateAccessPolicy)?
+            .id()
+            .to_string();
+        created.policy_ids.push(access_policy_id.clone());
+
+        let contract_policy_id = if request.shares_policy_id() {
+            access_policy_id.clone()
+        } else {
+            let contract_policy = NewPolicyDefinition::builder()
+                .maybe_id(request.contract_policy_id.clone())
+                .policy(request.contract_policy.clone())
+                .build();
+
+            let contract_policy_id = self
+                .0
+                .policies()
+                .create(&contract_policy)
+                .await
+                .step(WorkflowStep::CreateContractPolicy)?
+                .id()
+                .to_string();
+            created.policy_ids.push(contract_policy_id.clone());
+            contract_policy_id
+        };
+
+        let contract_definition = NewContractDefinition::builder()
+            .maybe_id(request.contract_definition_id.clone())
+            .access_policy_id(&access_policy_id)
+            .contract_policy_id(&contract_policy_id)
+            .asset_selector(Criterion::new(
+                &format!("{}id", EDC_NAMESPACE),
+                "=",
+                asset_id.as_str(),
+            ))
+            .build();
+
+        let contract_definition_id = self
+            .0
+            .contract_definitions()
+            .create(&contract_definition)
+            .await
+            .step(WorkflowStep::CreateContractDefinition)?
+            .id()
+            .to_string();
+
+        Ok(PublishedOffering {
+            asset_id,
+            access_policy_id,
+            contract_policy_id,
+            contract_definition_id,
+        })
+    }
+
+    async fn rollback(&self, created: Created) {
+        for policy_id in created.policy_ids.iter().rev() {
+            if let Err(err) = self.0.policies().delete(policy_id).await {
+                warn!(policy_id, error = %err, "Failed to roll back policy definition");
+            }
+        }
+
+        if let Some(asset_id) = created.asset_id {
+            if let Err(err) = self.0.assets().delete(&asset_id).await {
+                warn!(asset_id, error = %err, "Failed to roll back asset");
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Created {
+    asset_id: Option<String>,
+    policy_ids: Vec<String>,
+}
+
+fn ignore_not_found(result: EdcResult<()>) -> EdcResult<()> {
+    match result {
+        Err(err) if err.is_not_found() => Ok(()),
+        result => result,
+    }
+}
@@ -65,7 +65,8 @@ mod assets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::CONFLICT,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -120,7 +121,8 @@ mod assets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -213,7 +215,8 @@ mod assets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -285,7 +288,8 @@ mod assets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -448,4 +452,201 @@ mod assets {
             )
         }
     }
+    mod query_stream {
+        use edc_connector_client::{
+            types::{
+                asset::NewAsset,
+                data_address::DataAddress,
+                query::{Pagination, Query, SortOrder},
+            },
+            EDC_NAMESPACE,
+        };
+        use futures::TryStreamExt;
+        use rstest::rstest;
+        use uuid::Uuid;
+
+        use crate::common::{
+            provider_v3, provider_v4, provider_virtual_edc, setup_client, ClientParams,
+        };
+
+        async fn seed_group(
+            client: &edc_connector_client::EdcConnectorClient,
+            size: usize,
+        ) -> String {
+            let group = Uuid::new_v4().to_string();
+            for idx in 0..size {
+                let new_asset = NewAsset::builder()
+                    .id(Uuid::new_v4().to_string().as_str())
+                    .property("foo", format!("bar-{}", idx))
+                    .property("group", &group)
+                    .data_address(DataAddress::builder().kind("type").build().unwrap())
+                    .build();
+
+                client.assets().create(&new_asset).await.unwrap();
+            }
+            group
+        }
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[case(provider_virtual_edc())]
+        #[tokio::test]
+        async fn should_stream_all_pages(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let group = seed_group(&client, 3).await;
+
+            let query = Query::builder()
+                .filter(&format!("{}{}", EDC_NAMESPACE, "group"), "=", &group)
+                .sort(&format!("{}{}", EDC_NAMESPACE, "foo"), SortOrder::Asc)
+                .limit(1)
+                .build();
+
+            let assets = client
+                .assets()
+                .query_stream(query)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+
+            assert_eq!(3, assets.len());
+
+            let values = assets
+                .iter()
+                .map(|asset| asset.property::<String>("foo").unwrap().unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(vec!["bar-0", "bar-1", "bar-2"], values);
+        }
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[case(provider_virtual_edc())]
+        #[tokio::test]
+        async fn should_stream_up_to_max_items(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let group = seed_group(&client, 3).await;
+
+            let query = Query::builder()
+                .filter(&format!("{}{}", EDC_NAMESPACE, "group"), "=", &group)
+                .build();
+
+            let pagination = Pagination::builder().page_size(2).max_items(2).build();
+
+            let assets = client
+                .assets()
+                .query_stream_with(query, pagination)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+
+            assert_eq!(2, assets.len());
+        }
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[case(provider_virtual_edc())]
+        #[tokio::test]
+        async fn should_query_all(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let group = seed_group(&client, 3).await;
+
+            let query = Query::builder()
+                .filter(&format!("{}{}", EDC_NAMESPACE, "group"), "=", &group)
+                .limit(2)
+                .build();
+
+            let assets = client.assets().query_all(query).await.unwrap();
+
+            assert_eq!(3, assets.len());
+        }
+    }
+    mod upsert {
+        use edc_connector_client::types::{
+            asset::Asset, data_address::DataAddress, response::UpsertOutcome,
+        };
+        use rstest::rstest;
+        use uuid::Uuid;
+
+        use crate::common::{
+            provider_v3, provider_v4, provider_virtual_edc, setup_client, ClientParams,
+        };
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[case(provider_virtual_edc())]
+        #[tokio::test]
+        async fn should_create_then_update_an_asset(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let id = Uuid::new_v4().to_string();
+
+            let asset = Asset::builder()
+                .id(&id)
+                .property("foo", "bar")
+                .data_address(DataAddress::builder().kind("type").build().unwrap())
+                .build();
+
+            let outcome = client.assets().upsert(&asset).await.unwrap();
+
+            assert_eq!(UpsertOutcome::Created, outcome);
+
+            let asset = Asset::builder()
+                .id(&id)
+                .property("foo", "bar2")
+                .data_address(DataAddress::builder().kind("type").build().unwrap())
+                .build();
+
+            let outcome = client.assets().upsert(&asset).await.unwrap();
+
+            assert_eq!(UpsertOutcome::Updated, outcome);
+
+            let asset = client.assets().get(&id).await.unwrap();
+
+            assert_eq!("bar2", asset.property::<String>("foo").unwrap().unwrap())
+        }
+    }
+    mod bulk {
+        use edc_connector_client::types::{asset::NewAsset, data_address::DataAddress};
+        use rstest::rstest;
+        use uuid::Uuid;
+
+        use crate::common::{
+            provider_v3, provider_v4, provider_virtual_edc, setup_client, ClientParams,
+        };
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[case(provider_virtual_edc())]
+        #[tokio::test]
+        async fn should_create_and_delete_many_assets(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let ids = (0..5)
+                .map(|_| Uuid::new_v4().to_string())
+                .collect::<Vec<_>>();
+
+            let assets = ids.iter().map(|id| {
+                NewAsset::builder()
+                    .id(id)
+                    .data_address(DataAddress::builder().kind("type").build().unwrap())
+                    .build()
+            });
+
+            let report = client.assets().create_many(assets, 2).await;
+
+            assert!(report.is_success());
+            assert_eq!(5, report.len());
+
+            let report = client.assets().delete_many(ids.clone(), 2).await;
+
+            assert!(report.is_success());
+
+            for id in ids {
+                assert!(client.assets().get(&id).await.unwrap_err().is_not_found());
+            }
+        }
+    }
 }
@@ -26,8 +26,115 @@ mod auth {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::UNAUTHORIZED,
-                error_detail: ManagementApiErrorDetailKind::Parsed(_)
+                error_detail: ManagementApiErrorDetailKind::Parsed(_),
+                ..
             }))
         ));
     }
 }
+
+mod providers {
+    use edc_connector_client::{
+        transport::HttpRequest, Auth, AuthProvider, EdcResult, Error, ErrorKind,
+    };
+    use futures::future::BoxFuture;
+    use reqwest::{
+        header::{HeaderValue, AUTHORIZATION},
+        StatusCode,
+    };
+
+    use crate::common::fake::{asset, FakeTransport};
+
+    struct Signer;
+
+    impl AuthProvider for Signer {
+        fn authenticate<'a>(
+            &'a self,
+            request: &'a mut HttpRequest,
+        ) -> BoxFuture<'a, EdcResult<()>> {
+            Box::pin(async move {
+                let signature = format!("{} {}", request.method, request.url);
+                let value =
+                    HeaderValue::from_str(&signature).map_err(|e| Error::Auth(Box::new(e)))?;
+                request.headers.insert("x-signature", value);
+                Ok(())
+            })
+        }
+    }
+
+    struct Failing;
+
+    impl AuthProvider for Failing {
+        fn authenticate<'a>(
+            &'a self,
+            _request: &'a mut HttpRequest,
+        ) -> BoxFuture<'a, EdcResult<()>> {
+            Box::pin(async move { Err(Error::Auth("credentials unavailable".into())) })
+        }
+    }
+
+    #[tokio::test]
+    async fn should_send_bearer_token() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, asset("1"));
+
+        transport
+            .client_with_auth(Auth::bearer("secret-token"))
+            .assets()
+            .get("1")
+            .await
+            .unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!("Bearer secret-token", request.headers[AUTHORIZATION]);
+        assert!(request.headers.get("x-api-key").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_send_basic_credentials() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, asset("1"));
+
+        transport
+            .client_with_auth(Auth::basic("user", "pass"))
+            .assets()
+            .get("1")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            "Basic dXNlcjpwYXNz",
+            transport.requests()[0].headers[AUTHORIZATION]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_use_custom_provider() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, asset("1"));
+
+        let client = transport.client_with_auth(Auth::custom(Signer));
+        client.assets().get("1").await.unwrap();
+
+        assert_eq!(
+            "GET http://fake-connector/management/v3/assets/1",
+            transport.requests()[0].headers["x-signature"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_send_request_when_provider_fails() {
+        let transport = FakeTransport::default();
+
+        let err = transport
+            .client_with_auth(Auth::custom(Failing))
+            .assets()
+            .get("1")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Auth(_)));
+        assert_eq!(ErrorKind::Other, err.kind());
+        assert!(transport.requests().is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+mod builder {
+    use std::time::Duration;
+
+    use edc_connector_client::{BuilderError, EdcConnectorClient, Error};
+    use reqwest::Client;
+    use tokio::net::TcpListener;
+
+    #[allow(clippy::unwrap_used)]
+    async fn silent_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        format!("http://{}/management", addr)
+    }
+
+    #[tokio::test]
+    async fn should_apply_the_request_timeout() {
+        let client = EdcConnectorClient::builder()
+            .management_url(silent_server().await)
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let response = client.assets().get("1").await;
+
+        assert!(matches!(response, Err(Error::Reqwest(err)) if err.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn should_use_a_preconfigured_http_client() {
+        let http_client = Client::builder()
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let client = EdcConnectorClient::builder()
+            .management_url(silent_server().await)
+            .http_client(http_client)
+            .build()
+            .unwrap();
+
+        let response = client.assets().get("1").await;
+
+        assert!(matches!(response, Err(Error::Reqwest(err)) if err.is_timeout()));
+    }
+
+    #[test]
+    fn should_reject_a_preconfigured_client_with_transport_options() {
+        let result = EdcConnectorClient::builder()
+            .management_url("http://localhost")
+            .http_client(Client::new())
+            .timeout(Duration::from_secs(1))
+            .build();
+
+        assert!(
+            matches!(result, Err(BuilderError::InvalidProperty(property, _)) if property == "http_client")
+        );
+    }
+}
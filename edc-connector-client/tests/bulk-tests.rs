@@ -0,0 +1,83 @@
+mod common;
+
+mod bulk {
+    use edc_connector_client::types::{asset::NewAsset, data_address::DataAddress};
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::common::fake::FakeTransport;
+
+    #[allow(clippy::unwrap_used)]
+    fn new_asset(id: &str) -> NewAsset {
+        NewAsset::builder()
+            .id(id)
+            .data_address(DataAddress::builder().kind("type").build().unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn should_report_each_created_item() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::OK,
+                json!({ "@context": {}, "@id": "1", "createdAt": 1 }),
+            )
+            .respond(StatusCode::CONFLICT, json!([]))
+            .respond(
+                StatusCode::OK,
+                json!({ "@context": {}, "@id": "3", "createdAt": 1 }),
+            );
+
+        let report = transport
+            .client()
+            .assets()
+            .create_many(vec![new_asset("1"), new_asset("2"), new_asset("3")], 1)
+            .await;
+
+        assert_eq!(3, report.len());
+        assert!(!report.is_success());
+
+        let created = report
+            .succeeded()
+            .map(|(_, response)| response.id().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["1", "3"], created);
+
+        let failed = report.failed().collect::<Vec<_>>();
+        assert_eq!(1, failed.len());
+        assert!(failed[0].1.is_conflict());
+    }
+
+    #[tokio::test]
+    async fn should_delete_with_bounded_concurrency() {
+        let transport = FakeTransport::default();
+        for _ in 0..5 {
+            transport.respond(StatusCode::NO_CONTENT, json!(null));
+        }
+
+        let ids = (0..5).map(|idx| idx.to_string()).collect::<Vec<_>>();
+
+        let report = transport
+            .client()
+            .policies()
+            .delete_many(ids.clone(), 3)
+            .await;
+
+        assert!(report.is_success());
+        assert_eq!(
+            ids,
+            report
+                .results()
+                .iter()
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>()
+        );
+
+        let requests = transport.requests();
+        assert_eq!(5, requests.len());
+        assert!(requests
+            .iter()
+            .all(|request| request.method == Method::DELETE));
+    }
+}
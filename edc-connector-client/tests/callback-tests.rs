@@ -0,0 +1,380 @@
+#![cfg(feature = "callbacks")]
+
+mod common;
+
+mod events {
+    use edc_connector_client::callbacks::{
+        AssetEventKind, ContractNegotiationEventKind, Event, EventEnvelope,
+        TransferProcessEventKind,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn should_parse_a_transfer_process_event() {
+        let envelope = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-1",
+            "at": 1700000000000i64,
+            "type": "TransferProcessStarted",
+            "payload": {
+                "transferProcessId": "tp-1",
+                "assetId": "asset-1",
+                "contractId": "agreement-1",
+                "type": "CONSUMER",
+                "dataAddress": { "properties": { "endpoint": "http://provider/public" } },
+                "callbackAddresses": []
+            }
+        }))
+        .unwrap();
+
+        assert_eq!("event-1", envelope.id());
+        assert_eq!(1700000000000, envelope.at());
+        assert_eq!("TransferProcessStarted", envelope.event().kind());
+        match envelope.into_event() {
+            Event::TransferProcess(TransferProcessEventKind::Started, event) => {
+                assert_eq!("tp-1", event.transfer_process_id());
+                assert_eq!(Some("asset-1"), event.asset_id());
+                assert_eq!(Some("CONSUMER"), event.process_type());
+                assert!(event.data_address().is_some());
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn should_parse_a_contract_negotiation_event() {
+        let envelope = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-2",
+            "at": 1,
+            "type": "ContractNegotiationFinalized",
+            "payload": {
+                "contractNegotiationId": "cn-1",
+                "counterPartyAddress": "http://provider/protocol",
+                "counterPartyId": "provider",
+                "protocol": "dataspace-protocol-http",
+                "contractAgreement": { "id": "agreement-1", "assetId": "asset-1" }
+            }
+        }))
+        .unwrap();
+
+        match envelope.event() {
+            Event::ContractNegotiation(ContractNegotiationEventKind::Finalized, event) => {
+                assert_eq!("cn-1", event.contract_negotiation_id());
+                assert_eq!(Some("agreement-1"), event.contract_agreement_id());
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn should_parse_asset_and_unknown_events() {
+        let asset = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-3", "at": 1, "type": "AssetCreated", "payload": { "assetId": "asset-1" }
+        }))
+        .unwrap();
+        let unknown = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-4", "at": 1, "type": "PolicyDefinitionCreated", "payload": { "policyDefinitionId": "p-1" }
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            asset.event(),
+            Event::Asset(AssetEventKind::Created, event) if event.asset_id() == "asset-1"
+        ));
+        assert!(matches!(
+            unknown.event(),
+            Event::Other { kind, payload } if kind == "PolicyDefinitionCreated" && payload["policyDefinitionId"] == "p-1"
+        ));
+    }
+}
+
+mod receiver {
+    use std::sync::{Arc, Mutex};
+
+    use edc_connector_client::callbacks::CallbackReceiver;
+    use reqwest::StatusCode;
+    use serde_json::{json, Value};
+    use tokio::sync::mpsc;
+
+    fn event() -> Value {
+        json!({
+            "id": "event-1",
+            "at": 1,
+            "type": "TransferProcessCompleted",
+            "payload": { "transferProcessId": "tp-1" }
+        })
+    }
+
+    #[tokio::test]
+    async fn should_dispatch_events_to_channels_and_handlers() {
+        let (sender, mut events) = mpsc::channel(1);
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let seen = handled.clone();
+
+        let receiver = CallbackReceiver::builder()
+            .auth("X-Api-Key", "secret")
+            .channel(sender)
+            .handler(move |envelope| {
+                let seen = seen.clone();
+                async move { seen.lock().unwrap().push(envelope.id().to_string()) }
+            })
+            .build()
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap();
+
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/callbacks", receiver.local_addr()))
+            .header("X-Api-Key", "secret")
+            .json(&event())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+        assert_eq!("event-1", events.recv().await.unwrap().id());
+        assert_eq!(vec!["event-1".to_string()], *handled.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn should_reject_events_without_a_valid_auth_header() {
+        let (sender, mut events) = mpsc::channel(1);
+
+        let receiver = CallbackReceiver::builder()
+            .auth("X-Api-Key", "secret")
+            .channel(sender)
+            .build()
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap();
+        let url = format!("http://{}/callbacks", receiver.local_addr());
+        let client = reqwest::Client::new();
+
+        let missing = client.post(&url).json(&event()).send().await.unwrap();
+        let invalid = client
+            .post(&url)
+            .header("X-Api-Key", "wrong")
+            .json(&event())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::UNAUTHORIZED, missing.status());
+        assert_eq!(StatusCode::UNAUTHORIZED, invalid.status());
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_events() {
+        let receiver = CallbackReceiver::builder()
+            .build()
+            .bind("127.0.0.1:0")
+            .await
+            .unwrap();
+
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/callbacks", receiver.local_addr()))
+            .json(&json!({ "foo": "bar" }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+}
+
+mod waiting {
+    use std::time::{Duration, Instant};
+
+    use edc_connector_client::{
+        callbacks::{CallbackEvents, CallbackWait, EventEnvelope},
+        types::{
+            contract_negotiation::{ContractNegotiationState, ContractRequest},
+            policy::{Policy, PolicyKind, Target},
+            transfer_process::{TransferProcessState, TransferRequest},
+        },
+        WaitOptions,
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use crate::common::fake::{negotiation, transfer, FakeTransport};
+
+    fn slow_polling() -> WaitOptions {
+        WaitOptions::builder()
+            .timeout(Duration::from_secs(30))
+            .poll_interval(Duration::from_secs(10))
+            .build()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn publish_later(events: &CallbackEvents, event: Value) {
+        let events = events.clone();
+        let envelope = serde_json::from_value::<EventEnvelope>(event).unwrap();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            events.publish(envelope);
+        });
+    }
+
+    #[tokio::test]
+    async fn should_complete_a_negotiation_on_the_callback_event() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::OK,
+                json!({ "@context": {}, "@id": "cn-1", "createdAt": 1 }),
+            )
+            .respond(StatusCode::OK, negotiation("FINALIZED"));
+
+        let events = CallbackEvents::default();
+        let callbacks = CallbackWait::builder()
+            .events(events.clone())
+            .uri("http://consumer/callbacks")
+            .auth("X-Api-Key", "callback-secret")
+            .grace_period(Duration::from_secs(30))
+            .build();
+
+        publish_later(
+            &events,
+            json!({
+                "id": "event-1",
+                "at": 1,
+                "type": "ContractNegotiationFinalized",
+                "payload": { "contractNegotiationId": "cn-1" }
+            }),
+        );
+
+        let request = ContractRequest::builder()
+            .counter_party_address("http://provider/protocol")
+            .counter_party_id("provider")
+            .policy(
+                Policy::builder()
+                    .kind(PolicyKind::Offer)
+                    .id("offer-1")
+                    .assigner("provider")
+                    .target(Target::simple("asset-1"))
+                    .build(),
+            )
+            .build();
+
+        let started = Instant::now();
+        let negotiation = transport
+            .client()
+            .contract_negotiations()
+            .initiate_and_wait(
+                request,
+                ContractNegotiationState::Finalized,
+                slow_polling(),
+                &callbacks,
+            )
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(&ContractNegotiationState::Finalized, negotiation.state());
+
+        let requests = transport.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!(Method::POST, requests[0].method);
+        let callback = &requests[0].body.as_ref().unwrap()["callbackAddresses"][0];
+        assert_eq!("http://consumer/callbacks", callback["uri"]);
+        assert_eq!(json!(["contract.negotiation"]), callback["events"]);
+        assert_eq!("X-Api-Key", callback["authKey"]);
+        assert_eq!("callback-secret", callback["authCodeId"]);
+    }
+
+    #[tokio::test]
+    async fn should_ignore_events_of_other_processes() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::OK,
+                json!({ "@context": {}, "@id": "tp-1", "createdAt": 1 }),
+            )
+            .respond(StatusCode::OK, transfer("STARTED"));
+
+        let events = CallbackEvents::default();
+        let callbacks = CallbackWait::builder()
+            .events(events.clone())
+            .uri("http://consumer/callbacks")
+            .grace_period(Duration::from_millis(300))
+            .build();
+
+        publish_later(
+            &events,
+            json!({
+                "id": "event-1",
+                "at": 1,
+                "type": "TransferProcessStarted",
+                "payload": { "transferProcessId": "tp-2" }
+            }),
+        );
+
+        let request = TransferRequest::builder()
+            .counter_party_address("http://provider/protocol")
+            .contract_id("agreement-1")
+            .transfer_type("HttpData-PULL")
+            .build();
+
+        let started = Instant::now();
+        let transfer = transport
+            .client()
+            .transfer_processes()
+            .initiate_and_wait(
+                request,
+                TransferProcessState::Started,
+                WaitOptions::default(),
+                &callbacks,
+            )
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(300));
+        assert_eq!(&TransferProcessState::Started, transfer.state());
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_polling_without_events() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::OK,
+                json!({ "@context": {}, "@id": "tp-1", "createdAt": 1 }),
+            )
+            .respond(StatusCode::OK, transfer("REQUESTED"))
+            .respond(StatusCode::OK, transfer("STARTED"));
+
+        let callbacks = CallbackWait::builder()
+            .events(CallbackEvents::default())
+            .uri("http://consumer/callbacks")
+            .grace_period(Duration::from_millis(10))
+            .build();
+
+        let request = TransferRequest::builder()
+            .counter_party_address("http://provider/protocol")
+            .contract_id("agreement-1")
+            .transfer_type("HttpData-PULL")
+            .build();
+
+        let transfer = transport
+            .client()
+            .transfer_processes()
+            .initiate_and_wait(
+                request,
+                TransferProcessState::Started,
+                WaitOptions::builder()
+                    .poll_interval(Duration::from_millis(1))
+                    .build(),
+                &callbacks,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(&TransferProcessState::Started, transfer.state());
+        assert_eq!(3, transport.requests().len());
+        assert_eq!(
+            json!(["transfer.process"]),
+            transport.requests()[0].body.as_ref().unwrap()["callbackAddresses"][0]["events"]
+        );
+    }
+}
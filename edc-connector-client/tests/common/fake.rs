@@ -0,0 +1,151 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use edc_connector_client::{
+    transport::{HttpRequest, HttpResponse, Transport},
+    Auth, EdcConnectorClient, EdcResult, Error,
+};
+use futures::future::BoxFuture;
+use reqwest::{header::HeaderMap, StatusCode};
+use serde_json::{json, Value};
+
+pub const FAKE_MANAGEMENT_URL: &str = "http://fake-connector/management";
+pub const EDR_ENDPOINT: &str = "http://provider:19291/public";
+
+#[derive(Clone, Default)]
+pub struct FakeTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+    responses: Arc<Mutex<VecDeque<EdcResult<HttpResponse>>>>,
+    delay: Arc<Mutex<Option<Duration>>>,
+}
+
+#[allow(clippy::unwrap_used)]
+impl FakeTransport {
+    pub fn respond(&self, status: StatusCode, body: Value) -> &Self {
+        self.responses.lock().unwrap().push_back(Ok(HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: serde_json::to_vec(&body).unwrap(),
+        }));
+        self
+    }
+
+    pub fn fail(&self, error: &str) -> &Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back(Err(Error::Transport(error.into())));
+        self
+    }
+
+    /// Answers every request only after `delay`, so concurrent calls overlap.
+    pub fn delay(&self, delay: Duration) -> &Self {
+        *self.delay.lock().unwrap() = Some(delay);
+        self
+    }
+
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn client(&self) -> EdcConnectorClient {
+        self.client_with_auth(Auth::api_token("123456"))
+    }
+
+    pub fn client_with_auth(&self, auth: Auth) -> EdcConnectorClient {
+        EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .with_auth(auth)
+            .transport(self.clone())
+            .build()
+            .unwrap()
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+impl Transport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, EdcResult<HttpResponse>> {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().pop_front();
+        let delay = *self.delay.lock().unwrap();
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            response.unwrap_or_else(|| {
+                Ok(HttpResponse {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    headers: HeaderMap::new(),
+                    body: b"no response queued".to_vec(),
+                })
+            })
+        })
+    }
+}
+
+/// A consumer contract negotiation in `state`, with an agreement once it is finalized.
+pub fn negotiation(state: &str) -> Value {
+    json!({
+        "@context": {},
+        "@id": "cn-1",
+        "state": state,
+        "contractAgreementId": if state == "FINALIZED" { json!("agreement-1") } else { Value::Null },
+        "counterPartyId": "provider",
+        "counterPartyAddress": "http://provider/protocol",
+        "protocol": "dataspace-protocol-http",
+        "createdAt": 1,
+        "callbackAddresses": [],
+        "type": "CONSUMER"
+    })
+}
+
+/// A consumer pull transfer process in `state`.
+pub fn transfer(state: &str) -> Value {
+    json!({
+        "@context": {},
+        "@id": "tp-1",
+        "state": state,
+        "stateTimestamp": 1,
+        "assetId": "asset-1",
+        "contractId": "agreement-1",
+        "callbackAddresses": [],
+        "transferType": "HttpData-PULL",
+        "type": "CONSUMER"
+    })
+}
+
+/// The `IdResponse` returned by create calls.
+pub fn id_response(id: &str) -> Value {
+    json!({ "@context": {}, "@id": id, "createdAt": 1 })
+}
+
+/// An HTTP asset with a single `foo` property.
+pub fn asset(id: &str) -> Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": id,
+        "@type": "Asset",
+        "properties": { "foo": "bar" },
+        "dataAddress": { "@type": "DataAddress", "type": "HttpData" }
+    })
+}
+
+/// An EDR data address for `endpoint`, with its access token and refresh properties.
+pub fn edr(endpoint: &str, token: &str, expires_in: Value) -> Value {
+    json!({
+        "@context": {},
+        "@type": "DataAddress",
+        "type": "https://w3id.org/idsa/v4.1/HTTP",
+        "endpoint": endpoint,
+        "endpointType": "https://w3id.org/idsa/v4.1/HTTP",
+        "authType": "bearer",
+        "authorization": token,
+        "refreshToken": "eyJ.refresh",
+        "refreshEndpoint": "http://provider:19291/token",
+        "refreshAudience": "did:web:consumer",
+        "expiresIn": expires_in
+    })
+}
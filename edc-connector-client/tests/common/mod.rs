@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+pub mod fake;
+
 use std::{collections::HashMap, future::Future, thread, time::Duration};
 
 use bon::Builder;
@@ -16,7 +18,7 @@ use edc_connector_client::{
         transfer_process::{TransferProcessState, TransferRequest},
         Protocol,
     },
-    Auth, EdcConnectorApiVersion, EdcConnectorClient, OAuth2Config, EDC_NAMESPACE,
+    Auth, EdcConnectorApiVersion, EdcConnectorClient, OAuth2Config, WaitOptions, EDC_NAMESPACE,
 };
 use tokio::time::sleep;
 use uuid::Uuid;
@@ -404,25 +406,11 @@ pub async fn wait_for_negotiation_state(
     id: &str,
     state: ContractNegotiationState,
 ) {
-    wait_for(|| {
-        let i_state = state.clone();
-        async {
-            client
-                .contract_negotiations()
-                .get_state(id)
-                .await
-                .map_err(|err| err.to_string())
-                .and_then(|s| {
-                    if s == state {
-                        Ok(i_state)
-                    } else {
-                        Err("State mismatch".to_string())
-                    }
-                })
-        }
-    })
-    .await
-    .unwrap();
+    client
+        .contract_negotiations()
+        .wait_for_state(id, state, WaitOptions::default())
+        .await
+        .unwrap();
 }
 
 #[allow(clippy::unwrap_used)]
@@ -431,25 +419,11 @@ pub async fn wait_for_transfer_state(
     id: &str,
     state: TransferProcessState,
 ) {
-    wait_for(|| {
-        let i_state = state.clone();
-        async {
-            client
-                .transfer_processes()
-                .get_state(id)
-                .await
-                .map_err(|err| err.to_string())
-                .and_then(|s| {
-                    if s == state {
-                        Ok(i_state)
-                    } else {
-                        Err("State mismatch".to_string())
-                    }
-                })
-        }
-    })
-    .await
-    .unwrap();
+    client
+        .transfer_processes()
+        .wait_for_state(id, state, WaitOptions::default())
+        .await
+        .unwrap();
 }
 
 #[allow(clippy::unwrap_used)]
@@ -0,0 +1,166 @@
+mod config {
+    use std::{env, time::Duration};
+
+    use edc_connector_client::{
+        AuthConfig, BuilderError, EdcClientConfig, EdcClientConnectorBuilder,
+        EdcConnectorApiVersion,
+    };
+    use uuid::Uuid;
+
+    const JSON_CONFIG: &str = r#"{
+        "management_url": "http://localhost:29193/management",
+        "version": "v4beta",
+        "participant_context": "provider",
+        "auth": {
+            "type": "oauth2",
+            "client_id": "provider",
+            "client_secret": "provider-secret",
+            "token_url": "http://localhost:8080/realms/edcv/protocol/openid-connect/token",
+            "extra_params": { "audience": "management-api" }
+        },
+        "timeout_ms": 5000,
+        "retry": { "max_attempts": 5, "initial_backoff_ms": 100 }
+    }"#;
+
+    #[test]
+    fn should_load_json_config() {
+        let config = EdcClientConfig::from_json(JSON_CONFIG).unwrap();
+
+        assert_eq!("http://localhost:29193/management", config.management_url);
+        assert_eq!(EdcConnectorApiVersion::V4, config.version);
+        assert_eq!(Some("provider".to_string()), config.participant_context);
+        assert_eq!(Some(Duration::from_secs(5)), config.timeout_ms);
+        assert!(
+            matches!(config.auth, AuthConfig::OAuth2 { ref client_id, .. } if client_id == "provider")
+        );
+
+        let retry = config.retry.clone().unwrap();
+        assert_eq!(Some(5), retry.max_attempts);
+        assert_eq!(Some(Duration::from_millis(100)), retry.initial_backoff_ms);
+
+        let client = EdcClientConnectorBuilder::from_config(config)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(EdcConnectorApiVersion::V4, client.api_version());
+    }
+
+    #[test]
+    fn should_default_to_v3_without_auth() {
+        let config =
+            EdcClientConfig::from_json(r#"{ "management_url": "http://localhost/management" }"#)
+                .unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V3, config.version);
+        assert!(matches!(config.auth, AuthConfig::None));
+    }
+
+    #[test]
+    fn should_reject_unknown_version() {
+        let result = EdcClientConfig::from_json(
+            r#"{ "management_url": "http://localhost/management", "version": "v2" }"#,
+        );
+
+        assert!(result.unwrap_err().to_string().contains("unsupported"));
+    }
+
+    #[test]
+    fn should_redact_secrets_in_debug_output() {
+        let config = EdcClientConfig::from_json(JSON_CONFIG).unwrap();
+        let debug = format!("{:?}", config);
+
+        assert!(debug.contains("provider"));
+        assert!(!debug.contains("provider-secret"));
+
+        let auth = [
+            r#"{ "type": "api_token", "token": "api-secret" }"#,
+            r#"{ "type": "bearer", "token": "api-secret" }"#,
+            r#"{ "type": "basic", "username": "admin", "password": "api-secret" }"#,
+        ];
+        for auth in auth {
+            let auth: AuthConfig = serde_json::from_str(auth).unwrap();
+            assert!(!format!("{:?}", auth).contains("api-secret"));
+        }
+    }
+
+    #[test]
+    fn should_load_config_file() {
+        let path = env::temp_dir().join(format!("{}.json", Uuid::new_v4()));
+        std::fs::write(&path, JSON_CONFIG).unwrap();
+
+        let config = EdcClientConfig::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V4, config.version);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn should_load_toml_config() {
+        let config = EdcClientConfig::from_toml(
+            r#"
+            management_url = "http://localhost:29193/management"
+            version = "v3"
+
+            [auth]
+            type = "api_token"
+            token = "123456"
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(config.auth, AuthConfig::ApiToken { ref token } if token == "123456"));
+    }
+
+    #[test]
+    fn should_load_config_from_env() {
+        let prefix = format!("EDC_{}", Uuid::new_v4().simple());
+        let set = |name: &str, value: &str| env::set_var(format!("{}_{}", prefix, name), value);
+
+        set("MANAGEMENT_URL", "http://localhost:29193/management");
+        set("API_VERSION", "V4");
+        set("PARTICIPANT_CONTEXT", "consumer");
+        set("OAUTH2_CLIENT_ID", "consumer");
+        set("OAUTH2_CLIENT_SECRET", "consumer-secret");
+        set("OAUTH2_TOKEN_URL", "http://localhost:8080/token");
+        set("OAUTH2_SCOPES", "management-api:read, management-api:write");
+        set("TIMEOUT_MS", "2500");
+        set("RETRY_MAX_ATTEMPTS", "4");
+
+        let config = EdcClientConfig::from_env(&prefix).unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V4, config.version);
+        assert_eq!(Some("consumer".to_string()), config.participant_context);
+        assert_eq!(Some(Duration::from_millis(2500)), config.timeout_ms);
+        assert_eq!(Some(4), config.retry.as_ref().unwrap().max_attempts);
+        assert!(matches!(
+            config.auth,
+            AuthConfig::OAuth2 { ref scopes, .. }
+                if scopes.as_deref() == Some(&["management-api:read".to_string(), "management-api:write".to_string()][..])
+        ));
+
+        let client = EdcClientConnectorBuilder::from_env(&prefix)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(EdcConnectorApiVersion::V4, client.api_version());
+    }
+
+    #[test]
+    fn should_report_missing_and_invalid_env_vars() {
+        let prefix = format!("EDC_{}", Uuid::new_v4().simple());
+
+        assert!(matches!(
+            EdcClientConfig::from_env(&prefix),
+            Err(BuilderError::MissingProperty(ref name)) if name == &format!("{}_MANAGEMENT_URL", prefix)
+        ));
+
+        env::set_var(format!("{}_MANAGEMENT_URL", prefix), "http://localhost");
+        env::set_var(format!("{}_TIMEOUT_MS", prefix), "soon");
+
+        assert!(matches!(
+            EdcClientConfig::from_env(&prefix),
+            Err(BuilderError::InvalidProperty(ref name, _)) if name == &format!("{}_TIMEOUT_MS", prefix)
+        ));
+    }
+}
@@ -76,7 +76,8 @@ mod contract_definition {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::CONFLICT,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -138,7 +139,8 @@ mod contract_definition {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -205,7 +207,8 @@ mod contract_definition {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -287,7 +290,8 @@ mod contract_definition {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -126,7 +126,8 @@ mod contract_negotiations {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::BAD_REQUEST,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -0,0 +1,218 @@
+mod common;
+
+mod data_plane {
+    use edc_connector_client::{
+        data_plane::{DataPlaneClient, DataPlaneError, DataPlaneRequest},
+        types::data_address::DataAddress,
+        EdcConnectorClient, Error,
+    };
+    use futures::TryStreamExt;
+    use reqwest::{
+        header::{AUTHORIZATION, USER_AGENT},
+        Method, StatusCode,
+    };
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{body_string, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::fake::{edr, FakeTransport};
+
+    #[allow(clippy::unwrap_used)]
+    fn data_address(endpoint: &str, token: &str) -> DataAddress {
+        serde_json::from_value(edr(endpoint, token, Value::Null)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_get_data_with_path_and_query() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/public/orders/1"))
+            .and(query_param("expand", "lines"))
+            .and(header(AUTHORIZATION, "token-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+            .mount(&server)
+            .await;
+
+        let client = DataPlaneClient::new(data_address(
+            &format!("{}/public/", server.uri()),
+            "token-1",
+        ))
+        .unwrap();
+
+        let response = client
+            .send(
+                DataPlaneRequest::builder()
+                    .path("/orders/1")
+                    .query_param("expand", "lines")
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            json!({ "id": 1 }),
+            response.json::<serde_json::Value>().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_proxy_method_and_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/public"))
+            .and(header("content-type", "text/plain"))
+            .and(body_string("hello"))
+            .respond_with(ResponseTemplate::new(201))
+            .mount(&server)
+            .await;
+
+        let client =
+            DataPlaneClient::new(data_address(&format!("{}/public", server.uri()), "token-1"))
+                .unwrap();
+
+        let response = client
+            .send(
+                DataPlaneRequest::builder()
+                    .method(Method::POST)
+                    .body("hello")
+                    .content_type("text/plain")
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::CREATED, response.status());
+    }
+
+    #[tokio::test]
+    async fn should_stream_the_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/public"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("a".repeat(64 * 1024)))
+            .mount(&server)
+            .await;
+
+        let client =
+            DataPlaneClient::new(data_address(&format!("{}/public", server.uri()), "token-1"))
+                .unwrap();
+
+        let chunks = client
+            .get("")
+            .await
+            .unwrap()
+            .bytes_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            64 * 1024,
+            chunks.iter().map(|chunk| chunk.len()).sum::<usize>()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refresh_the_token_on_unauthorized() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header(AUTHORIZATION, "expired"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(header(AUTHORIZATION, "fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("data"))
+            .mount(&server)
+            .await;
+
+        let endpoint = format!("{}/public", server.uri());
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!({
+                "@context": {},
+                "@type": "DataAddress",
+                "type": "https://w3id.org/idsa/v4.1/HTTP",
+                "endpoint": endpoint,
+                "authorization": "fresh"
+            }),
+        );
+
+        let client = DataPlaneClient::new(data_address(&endpoint, "expired"))
+            .unwrap()
+            .with_edr_refresh(transport.client(), "tp-1");
+
+        let body = client.get("").await.unwrap().text().await.unwrap();
+
+        assert_eq!("data", body);
+        assert_eq!(Some("fresh"), client.data_address().authorization());
+        assert!(transport.requests()[0]
+            .url
+            .ends_with("/v3/edrs/tp-1/refresh"));
+    }
+
+    #[tokio::test]
+    async fn should_reuse_the_management_http_client_with_edr_refresh() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header(USER_AGENT, "edc-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("data"))
+            .mount(&server)
+            .await;
+
+        let management = EdcConnectorClient::builder()
+            .management_url(server.uri())
+            .user_agent("edc-test")
+            .build()
+            .unwrap();
+
+        let client =
+            DataPlaneClient::new(data_address(&format!("{}/public", server.uri()), "token-1"))
+                .unwrap()
+                .with_edr_refresh(management, "tp-1");
+
+        let body = client.get("").await.unwrap().text().await.unwrap();
+
+        assert_eq!("data", body);
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_unauthorized_without_refresh() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("expired"))
+            .mount(&server)
+            .await;
+
+        let client =
+            DataPlaneClient::new(data_address(&format!("{}/public", server.uri()), "expired"))
+                .unwrap();
+
+        let err = client.get("").await.unwrap_err();
+
+        assert!(err.is_unauthorized());
+        assert!(matches!(
+            err,
+            Error::DataPlane(DataPlaneError::Status { ref body, .. }) if body == "expired"
+        ));
+    }
+
+    #[test]
+    fn should_reject_non_http_data_addresses() {
+        let data_address = serde_json::from_value::<DataAddress>(json!({
+            "@type": "DataAddress",
+            "type": "AmazonS3",
+            "endpoint": "http://bucket"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            DataPlaneClient::new(data_address),
+            Err(DataPlaneError::UnsupportedType(kind)) if kind == "AmazonS3"
+        ));
+    }
+}
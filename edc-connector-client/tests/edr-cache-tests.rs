@@ -0,0 +1,202 @@
+mod common;
+
+mod edr_cache {
+    use std::time::Duration;
+
+    use edc_connector_client::{EdrCache, EdrKey};
+    use reqwest::{Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use crate::common::fake::{edr, FakeTransport, EDR_ENDPOINT, FAKE_MANAGEMENT_URL};
+
+    fn state(state: &str) -> Value {
+        json!({ "@context": {}, "@type": "TransferState", "state": state })
+    }
+
+    fn urls(transport: &FakeTransport) -> Vec<String> {
+        transport
+            .requests()
+            .into_iter()
+            .map(|request| request.url.replace(FAKE_MANAGEMENT_URL, ""))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_serve_a_cached_edr() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("3600")));
+
+        let cache = EdrCache::new(transport.client());
+        let key = EdrKey::transfer_process("tp-1");
+
+        let first = cache.get(&key).await.unwrap();
+        let second = cache.get(&key).await.unwrap();
+
+        assert_eq!(Some("http://provider:19291/public"), first.endpoint());
+        assert_eq!(Some("token-1"), second.authorization());
+        assert_eq!(
+            vec!["/v3/edrs/tp-1/dataaddress?auto_refresh=true"],
+            urls(&transport)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_resolve_the_latest_edr_of_an_asset() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::OK,
+                json!([
+                    { "@context": {}, "@type": "EndpointDataReferenceEntry", "assetId": "asset-1", "agreementId": "a-1", "transferProcessId": "tp-old", "providerId": "provider", "createdAt": 1 },
+                    { "@context": {}, "@type": "EndpointDataReferenceEntry", "assetId": "asset-1", "agreementId": "a-2", "transferProcessId": "tp-new", "providerId": "provider", "createdAt": 2 }
+                ]),
+            )
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("3600")));
+
+        let cache = EdrCache::new(transport.client());
+
+        let edr = cache
+            .get(&EdrKey::asset("asset-1", "provider"))
+            .await
+            .unwrap();
+
+        assert_eq!("tp-new", edr.transfer_process_id());
+        assert_eq!(
+            vec![
+                "/v3/edrs/request",
+                "/v3/edrs/tp-new/dataaddress?auto_refresh=true"
+            ],
+            urls(&transport)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refresh_an_edr_about_to_expire() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("5")))
+            .respond(StatusCode::OK, state("STARTED"))
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-2", json!("3600")));
+
+        let cache = EdrCache::new(transport.client());
+        let key = EdrKey::transfer_process("tp-1");
+
+        cache.get(&key).await.unwrap();
+        let refreshed = cache.get(&key).await.unwrap();
+
+        assert_eq!(Some("token-2"), refreshed.authorization());
+        let requests = transport.requests();
+        assert_eq!(Method::POST, requests[2].method);
+        assert_eq!(
+            vec![
+                "/v3/edrs/tp-1/dataaddress?auto_refresh=true",
+                "/v3/transferprocesses/tp-1",
+                "/v3/edrs/tp-1/refresh"
+            ],
+            urls(&transport)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_share_a_single_load_between_concurrent_gets() {
+        let transport = FakeTransport::default();
+        transport
+            .delay(Duration::from_millis(20))
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("3600")));
+
+        let cache = EdrCache::new(transport.client());
+        let key = EdrKey::transfer_process("tp-1");
+
+        let (first, second, third) =
+            tokio::join!(cache.get(&key), cache.get(&key), cache.get(&key));
+
+        for edr in [first, second, third] {
+            assert_eq!(Some("token-1"), edr.unwrap().authorization());
+        }
+        assert_eq!(
+            vec!["/v3/edrs/tp-1/dataaddress?auto_refresh=true"],
+            urls(&transport)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_share_a_single_refresh_between_concurrent_gets() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("5")))
+            .respond(StatusCode::OK, state("STARTED"))
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-2", json!("3600")));
+
+        let cache = EdrCache::new(transport.client());
+        let key = EdrKey::transfer_process("tp-1");
+
+        cache.get(&key).await.unwrap();
+        transport.delay(Duration::from_millis(20));
+
+        let (first, second, third) =
+            tokio::join!(cache.get(&key), cache.get(&key), cache.get(&key));
+
+        for edr in [first, second, third] {
+            assert_eq!(Some("token-2"), edr.unwrap().authorization());
+        }
+        assert_eq!(
+            1,
+            urls(&transport)
+                .iter()
+                .filter(|url| url.ends_with("/refresh"))
+                .count()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_evict_when_the_transfer_terminated() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("5")))
+            .respond(StatusCode::OK, state("TERMINATED"));
+
+        let cache = EdrCache::new(transport.client());
+        let key = EdrKey::transfer_process("tp-1");
+
+        cache.get(&key).await.unwrap();
+        let err = cache.get(&key).await.unwrap_err();
+
+        assert!(err.is_not_found());
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_evict_terminated_transfers() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("3600")))
+            .respond(StatusCode::OK, state("COMPLETED"));
+
+        let cache = EdrCache::new(transport.client());
+        cache.get(&EdrKey::transfer_process("tp-1")).await.unwrap();
+
+        assert_eq!(1, cache.evict_terminated().await.unwrap());
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_evict_on_delete() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, edr(EDR_ENDPOINT, "token-1", json!("3600")))
+            .respond(StatusCode::NO_CONTENT, Value::Null);
+
+        let cache = EdrCache::new(transport.client());
+        cache.get(&EdrKey::transfer_process("tp-1")).await.unwrap();
+
+        cache.delete("tp-1").await.unwrap();
+
+        assert!(cache.is_empty());
+        let requests = transport.requests();
+        assert_eq!(Method::DELETE, requests[1].method);
+        assert_eq!(
+            format!("{}/v3/edrs/tp-1", FAKE_MANAGEMENT_URL),
+            requests[1].url
+        );
+    }
+}
@@ -137,8 +137,133 @@ mod delete {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::NOT_FOUND,
-                error_detail: ManagementApiErrorDetailKind::Raw(..)
+                error_detail: ManagementApiErrorDetailKind::Raw(..),
+                ..
             }))
         ))
     }
 }
+
+mod data_address {
+    use edc_connector_client::types::transfer_process::TransferProcessState;
+    use rstest::rstest;
+
+    use crate::common::{
+        consumer_v3, consumer_v4, provider_v3, provider_v4, seed_transfer_process, setup_client,
+        wait_for, wait_for_transfer_state, ClientParams,
+    };
+
+    #[rstest]
+    #[case(consumer_v3(), provider_v3())]
+    #[case(consumer_v4(), provider_v4())]
+    #[tokio::test]
+    async fn should_get_a_typed_edr_data_address(
+        #[case] consumer_cfg: ClientParams,
+        #[case] provider_cfg: ClientParams,
+    ) {
+        let provider = setup_client(provider_cfg.clone());
+        let consumer = setup_client(consumer_cfg.clone());
+
+        let (transfer_process_id, _, _, _) =
+            seed_transfer_process(&consumer, &consumer_cfg, &provider, &provider_cfg).await;
+
+        wait_for_transfer_state(
+            &consumer,
+            &transfer_process_id,
+            TransferProcessState::Started,
+        )
+        .await;
+
+        let data_address = wait_for(|| async {
+            consumer
+                .edrs()
+                .get_data_address_with_opts(&transfer_process_id, true)
+                .await
+        })
+        .await
+        .unwrap();
+
+        assert!(data_address.endpoint().is_some());
+        assert!(data_address.authorization().is_some());
+    }
+}
+
+mod edr_data_address {
+    use std::time::Duration;
+
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::common::fake::{edr, FakeTransport, EDR_ENDPOINT, FAKE_MANAGEMENT_URL};
+
+    #[tokio::test]
+    async fn should_expose_typed_edr_properties() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, edr(EDR_ENDPOINT, "eyJ.token", json!("300")));
+
+        let data_address = transport
+            .client()
+            .edrs()
+            .get_data_address("tp-1")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("http://provider:19291/public"),
+            data_address.endpoint()
+        );
+        assert_eq!(Some("eyJ.token"), data_address.authorization());
+        assert_eq!(Some("bearer"), data_address.auth_type());
+        assert_eq!(Some("eyJ.refresh"), data_address.refresh_token());
+        assert_eq!(
+            Some("http://provider:19291/token"),
+            data_address.refresh_endpoint()
+        );
+        assert_eq!(Some("did:web:consumer"), data_address.refresh_audience());
+        assert_eq!(Some(Duration::from_secs(300)), data_address.expires_in());
+        assert_eq!(
+            format!("{}/v3/edrs/tp-1/dataaddress", FAKE_MANAGEMENT_URL),
+            transport.requests()[0].url
+        );
+    }
+
+    #[tokio::test]
+    async fn should_request_auto_refresh() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, edr(EDR_ENDPOINT, "eyJ.token", json!(600)));
+
+        let data_address = transport
+            .client()
+            .edrs()
+            .get_data_address_with_opts("tp-1", true)
+            .await
+            .unwrap();
+
+        assert_eq!(Some(Duration::from_secs(600)), data_address.expires_in());
+        assert_eq!(
+            format!(
+                "{}/v3/edrs/tp-1/dataaddress?auto_refresh=true",
+                FAKE_MANAGEMENT_URL
+            ),
+            transport.requests()[0].url
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refresh_an_edr() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, edr(EDR_ENDPOINT, "eyJ.token", json!("300")));
+
+        let data_address = transport.client().edrs().refresh("tp-1").await.unwrap();
+
+        assert_eq!(Some("eyJ.token"), data_address.authorization());
+
+        let request = &transport.requests()[0];
+        assert_eq!(Method::POST, request.method);
+        assert_eq!(
+            format!("{}/v3/edrs/tp-1/refresh", FAKE_MANAGEMENT_URL),
+            request.url
+        );
+        assert!(request.body.is_none());
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+mod errors {
+    use edc_connector_client::{EdcConnectorClient, Error, ErrorKind};
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    use crate::common::fake::{FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[tokio::test]
+    async fn should_classify_error_responses() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(
+                StatusCode::BAD_REQUEST,
+                json!([{ "message": "missing @id", "type": "ValidationFailure" }]),
+            )
+            .respond(StatusCode::CONFLICT, json!([]))
+            .respond(StatusCode::FORBIDDEN, json!([]))
+            .respond(StatusCode::BAD_GATEWAY, json!("bad gateway"));
+
+        let client = transport.client();
+
+        let err = client.assets().get("1").await.unwrap_err();
+        assert_eq!(ErrorKind::BadRequest, err.kind());
+        assert!(err.is_bad_request());
+        let details = err.management_api_error().unwrap().details();
+        assert_eq!(1, details.len());
+        assert_eq!("ValidationFailure", details[0].kind);
+        assert_eq!(
+            Some((
+                &Method::GET,
+                format!("{}/v3/assets/1", FAKE_MANAGEMENT_URL).as_str()
+            )),
+            err.endpoint()
+        );
+
+        assert!(client.assets().delete("1").await.unwrap_err().is_conflict());
+        assert!(client.assets().get("1").await.unwrap_err().is_forbidden());
+
+        let err = client.assets().get("1").await.unwrap_err();
+        assert!(err.is_server_error());
+        assert!(err.management_api_error().unwrap().details().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_report_the_url_of_transport_failures() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let client = EdcConnectorClient::builder()
+            .management_url(format!("http://{}/management", addr))
+            .build()
+            .unwrap();
+
+        let err = client.assets().delete("1").await.unwrap_err();
+
+        assert_eq!(ErrorKind::Transport, err.kind());
+        assert!(matches!(err, Error::Reqwest(_)));
+        assert_eq!(
+            Some(format!("http://{}/management/v3/assets/1", addr).as_str()),
+            err.url()
+        );
+    }
+}
@@ -0,0 +1,445 @@
+mod common;
+
+mod client_assertion {
+    use std::collections::HashMap;
+
+    use edc_connector_client::{AssertionAlgorithm, Auth, ClientAssertion, OAuth2Config};
+    use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+    use reqwest::{header::AUTHORIZATION, StatusCode};
+    use serde::Deserialize;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::fake::FakeTransport;
+
+    const RSA_PRIVATE: &str = include_str!("fixtures/rsa-private.pem");
+    const RSA_PUBLIC: &str = include_str!("fixtures/rsa-public.pem");
+    const EC_PRIVATE: &str = include_str!("fixtures/ec-private.pem");
+    const EC_PUBLIC: &str = include_str!("fixtures/ec-public.pem");
+
+    #[derive(Deserialize)]
+    struct Claims {
+        iss: String,
+        sub: String,
+        jti: String,
+        iat: u64,
+        exp: u64,
+    }
+
+    async fn token_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "access-token",
+                "token_type": "bearer",
+                "expires_in": 300
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[allow(clippy::unwrap_used)]
+    async fn token_request_params(server: &MockServer) -> HashMap<String, String> {
+        let requests = server.received_requests().await.unwrap();
+        let body = String::from_utf8(requests[0].body.clone()).unwrap();
+
+        body.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.replace("%3A", ":")))
+            .collect()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    async fn call_management_api(auth: Auth) -> FakeTransport {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, json!([]));
+
+        transport
+            .client_with_auth(auth)
+            .assets()
+            .query(Default::default())
+            .await
+            .unwrap();
+
+        transport
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_with_rs256_assertion() {
+        let server = token_server().await;
+        let token_url = format!("{}/token", server.uri());
+
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .token_url(&token_url)
+            .client_assertion(
+                ClientAssertion::builder()
+                    .private_key_pem(RSA_PRIVATE)
+                    .key_id("key-1")
+                    .build(),
+            )
+            .build();
+
+        let transport = call_management_api(Auth::oauth(config).unwrap()).await;
+
+        assert_eq!(
+            "Bearer access-token",
+            transport.requests()[0].headers[AUTHORIZATION]
+        );
+
+        let params = token_request_params(&server).await;
+        assert_eq!("client_credentials", params["grant_type"]);
+        assert_eq!("consumer", params["client_id"]);
+        assert!(!params.contains_key("client_secret"));
+        assert_eq!(
+            "urn:ietf:params:oauth:client-assertion-type:jwt-bearer",
+            params["client_assertion_type"]
+        );
+
+        let assertion = &params["client_assertion"];
+        let header = decode_header(assertion).unwrap();
+        assert_eq!(Algorithm::RS256, header.alg);
+        assert_eq!(Some("key-1".to_string()), header.kid);
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(&[&token_url]);
+        let claims = decode::<Claims>(
+            assertion,
+            &DecodingKey::from_rsa_pem(RSA_PUBLIC.as_bytes()).unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims;
+
+        assert_eq!("consumer", claims.iss);
+        assert_eq!("consumer", claims.sub);
+        assert!(!claims.jti.is_empty());
+        assert!(claims.exp > claims.iat);
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_with_es256_assertion_and_custom_audience() {
+        let server = token_server().await;
+
+        let config = OAuth2Config::builder()
+            .client_id("provider")
+            .token_url(format!("{}/token", server.uri()))
+            .client_assertion(
+                ClientAssertion::builder()
+                    .private_key_pem(EC_PRIVATE)
+                    .algorithm(AssertionAlgorithm::ES256)
+                    .audience("https://idp.example.com")
+                    .build(),
+            )
+            .build();
+
+        call_management_api(Auth::oauth(config).unwrap()).await;
+
+        let params = token_request_params(&server).await;
+        let mut validation = Validation::new(Algorithm::ES256);
+        validation.set_audience(&["https://idp.example.com"]);
+
+        let claims = decode::<Claims>(
+            &params["client_assertion"],
+            &DecodingKey::from_ec_pem(EC_PUBLIC.as_bytes()).unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims;
+
+        assert_eq!("provider", claims.sub);
+    }
+
+    #[test]
+    fn should_reject_invalid_key() {
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .token_url("http://localhost/token")
+            .client_assertion(
+                ClientAssertion::builder()
+                    .private_key_pem("not a key")
+                    .build(),
+            )
+            .build();
+
+        assert!(Auth::oauth(config).is_err());
+    }
+
+    #[test]
+    fn should_reject_secret_and_assertion() {
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .token_url("http://localhost/token")
+            .client_assertion(
+                ClientAssertion::builder()
+                    .private_key_pem(RSA_PRIVATE)
+                    .build(),
+            )
+            .build();
+
+        assert!(Auth::oauth(config).is_err());
+    }
+}
+
+mod token_session {
+    use std::time::Duration;
+
+    use edc_connector_client::{Auth, ErrorKind, OAuth2Config};
+    use reqwest::{header::AUTHORIZATION, StatusCode};
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::fake::FakeTransport;
+
+    fn token(access_token: &str, expires_in: Option<u64>) -> Value {
+        let mut token = json!({ "access_token": access_token, "token_type": "bearer" });
+        if let Some(expires_in) = expires_in {
+            token["expires_in"] = json!(expires_in);
+        }
+        token
+    }
+
+    async fn token_server(tokens: Vec<Value>) -> MockServer {
+        let server = MockServer::start().await;
+        for (priority, token) in tokens.into_iter().enumerate() {
+            Mock::given(method("POST"))
+                .and(path("/token"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(token))
+                .up_to_n_times(1)
+                .with_priority(priority as u8 + 1)
+                .mount(&server)
+                .await;
+        }
+        server
+    }
+
+    fn config(server: &MockServer) -> OAuth2Config {
+        OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .token_url(format!("{}/token", server.uri()))
+            .build()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    async fn token_requests(server: &MockServer) -> usize {
+        server.received_requests().await.unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn should_refetch_token_on_unauthorized() {
+        let server = token_server(vec![
+            token("token-1", Some(3600)),
+            token("token-2", Some(3600)),
+        ])
+        .await;
+
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::UNAUTHORIZED, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let client = transport.client_with_auth(Auth::oauth(config(&server)).unwrap());
+        client.assets().query(Default::default()).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("Bearer token-1", requests[0].headers[AUTHORIZATION]);
+        assert_eq!("Bearer token-2", requests[1].headers[AUTHORIZATION]);
+        assert_eq!(2, token_requests(&server).await);
+    }
+
+    #[tokio::test]
+    async fn should_retry_only_once_on_unauthorized() {
+        let server = token_server(vec![
+            token("token-1", Some(3600)),
+            token("token-2", Some(3600)),
+        ])
+        .await;
+
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::UNAUTHORIZED, json!([]))
+            .respond(StatusCode::UNAUTHORIZED, json!([]));
+
+        let client = transport.client_with_auth(Auth::oauth(config(&server)).unwrap());
+        let err = client.assets().query(Default::default()).await.unwrap_err();
+
+        assert_eq!(ErrorKind::Unauthorized, err.kind());
+        assert_eq!(2, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_unauthorized_with_api_token() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::UNAUTHORIZED, json!([]));
+
+        let err = transport
+            .client()
+            .assets()
+            .query(Default::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::Unauthorized, err.kind());
+        assert_eq!(1, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_use_configured_expiry_skew() {
+        let server =
+            token_server(vec![token("token-1", Some(60)), token("token-2", Some(60))]).await;
+
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .token_url(format!("{}/token", server.uri()))
+            .expiry_skew(Duration::from_secs(120))
+            .build();
+
+        let client = transport.client_with_auth(Auth::oauth(config).unwrap());
+        client.assets().query(Default::default()).await.unwrap();
+        client.assets().query(Default::default()).await.unwrap();
+
+        assert_eq!(2, token_requests(&server).await);
+    }
+
+    #[tokio::test]
+    async fn should_use_configured_default_expiry() {
+        let server = token_server(vec![token("token-1", None), token("token-2", None)]).await;
+
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .token_url(format!("{}/token", server.uri()))
+            .default_expires_in(Duration::from_secs(3600))
+            .build();
+
+        let client = transport.client_with_auth(Auth::oauth(config).unwrap());
+        client.assets().query(Default::default()).await.unwrap();
+        client.assets().query(Default::default()).await.unwrap();
+
+        assert_eq!(1, token_requests(&server).await);
+        assert_eq!(
+            "Bearer token-1",
+            transport.requests()[1].headers[AUTHORIZATION]
+        );
+    }
+}
+
+mod discovery {
+    use edc_connector_client::{Auth, OAuth2Config};
+    use reqwest::{header::AUTHORIZATION, StatusCode};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{body_string_contains, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::fake::FakeTransport;
+
+    async fn identity_provider() -> MockServer {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/realms/edc/.well-known/openid-configuration"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "issuer": format!("{}/realms/edc", server.uri()),
+                "token_endpoint": format!("{}/realms/edc/protocol/openid-connect/token", server.uri())
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/realms/edc/protocol/openid-connect/token"))
+            .and(body_string_contains("audience=management-api"))
+            .and(body_string_contains("resource=urn%3Aedc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "discovered-token",
+                "token_type": "bearer",
+                "expires_in": 3600
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn should_discover_token_endpoint_from_issuer() {
+        let server = identity_provider().await;
+
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .issuer_url(format!("{}/realms/edc/", server.uri()))
+            .extra_param("audience", "management-api")
+            .extra_param("resource", "urn:edc")
+            .build();
+
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, json!([]))
+            .respond(StatusCode::UNAUTHORIZED, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let client = transport.client_with_auth(Auth::oauth(config).unwrap());
+        client.assets().query(Default::default()).await.unwrap();
+        client.assets().query(Default::default()).await.unwrap();
+
+        assert_eq!(
+            "Bearer discovered-token",
+            transport.requests()[0].headers[AUTHORIZATION]
+        );
+
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_discovery_fails() {
+        let server = MockServer::start().await;
+
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .issuer_url(server.uri())
+            .build();
+
+        let transport = FakeTransport::default();
+        let client = transport.client_with_auth(Auth::oauth(config).unwrap());
+
+        assert!(client.assets().query(Default::default()).await.is_err());
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn should_require_token_url_or_issuer() {
+        let config = OAuth2Config::builder()
+            .client_id("consumer")
+            .client_secret("secret")
+            .build();
+
+        assert!(Auth::oauth(config).is_err());
+    }
+}
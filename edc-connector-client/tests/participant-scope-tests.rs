@@ -0,0 +1,101 @@
+mod common;
+
+mod for_participant {
+    use edc_connector_client::{Auth, EdcConnectorApiVersion, EdcConnectorClient, OAuth2Config};
+    use reqwest::{header::AUTHORIZATION, StatusCode};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::common::fake::{FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[allow(clippy::unwrap_used)]
+    fn client(transport: &FakeTransport, auth: Auth) -> EdcConnectorClient {
+        EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .version(EdcConnectorApiVersion::V4)
+            .participant_context("provider")
+            .with_auth(auth)
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_scope_paths_to_the_participant() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let provider = client(&transport, Auth::bearer("token"));
+        let consumer = provider.for_participant("consumer");
+
+        assert_eq!(Some("consumer"), consumer.participant_context());
+        assert_eq!(Some("provider"), provider.participant_context());
+        assert_eq!(EdcConnectorApiVersion::V4, consumer.api_version());
+
+        consumer.assets().query(Default::default()).await.unwrap();
+        provider.assets().query(Default::default()).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(
+            format!(
+                "{}/v4alpha/participants/consumer/assets/request",
+                FAKE_MANAGEMENT_URL
+            ),
+            requests[0].url
+        );
+        assert_eq!(
+            format!(
+                "{}/v4alpha/participants/provider/assets/request",
+                FAKE_MANAGEMENT_URL
+            ),
+            requests[1].url
+        );
+        assert_eq!("Bearer token", requests[0].headers[AUTHORIZATION]);
+    }
+
+    #[tokio::test]
+    async fn should_share_the_oauth2_session() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "shared-token",
+                "token_type": "bearer",
+                "expires_in": 3600
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = OAuth2Config::builder()
+            .client_id("provisioner")
+            .client_secret("provisioner-secret")
+            .token_url(format!("{}/token", server.uri()))
+            .build();
+
+        let transport = FakeTransport::default();
+        let provider = client(&transport, Auth::oauth(config).unwrap());
+
+        for participant in ["a", "b", "c"] {
+            transport.respond(StatusCode::OK, json!([]));
+            provider
+                .for_participant(participant)
+                .assets()
+                .query(Default::default())
+                .await
+                .unwrap();
+        }
+
+        assert!(transport
+            .requests()
+            .iter()
+            .all(|request| request.headers[AUTHORIZATION] == "Bearer shared-token"));
+
+        server.verify().await;
+    }
+}
@@ -0,0 +1,180 @@
+mod common;
+
+mod participants {
+    use std::collections::HashMap;
+
+    use edc_connector_client::{
+        types::{
+            participants::{ParticipantContext, ParticipantContextState},
+            query::Query,
+        },
+        EdcConnectorApiVersion, EdcConnectorClient,
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use crate::common::fake::{FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[allow(clippy::unwrap_used)]
+    fn admin_client(transport: &FakeTransport) -> EdcConnectorClient {
+        EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .version(EdcConnectorApiVersion::V4)
+            .participant_context("provisioner")
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    fn participant(id: &str, state: &str) -> Value {
+        json!({
+            "@context": {},
+            "@id": id,
+            "@type": "ParticipantContext",
+            "identity": format!("did:web:{}", id),
+            "state": state,
+            "createdAt": 1,
+            "lastModified": 2,
+            "properties": { "tier": "gold" }
+        })
+    }
+
+    fn url(path: &str) -> String {
+        format!("{}/v4alpha/{}", FAKE_MANAGEMENT_URL, path)
+    }
+
+    #[tokio::test]
+    async fn should_get_participant() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, participant("consumer", "ACTIVATED"));
+
+        let participant = admin_client(&transport)
+            .participants()
+            .get("consumer")
+            .await
+            .unwrap();
+
+        assert_eq!("consumer", participant.id());
+        assert_eq!("did:web:consumer", participant.identity());
+        assert_eq!(
+            Some(&ParticipantContextState::Activated),
+            participant.state()
+        );
+        assert_eq!(
+            Some("gold".to_string()),
+            participant.property::<String>("tier").unwrap()
+        );
+        assert_eq!(url("participants/consumer"), transport.requests()[0].url);
+    }
+
+    #[tokio::test]
+    async fn should_query_participants() {
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!([
+                participant("consumer", "ACTIVATED"),
+                participant("provider", "DEACTIVATED")
+            ]),
+        );
+
+        let participants = admin_client(&transport)
+            .participants()
+            .query(Query::default())
+            .await
+            .unwrap();
+
+        assert_eq!(2, participants.len());
+        assert_eq!(
+            Some(&ParticipantContextState::Deactivated),
+            participants[1].state()
+        );
+
+        let request = &transport.requests()[0];
+        assert_eq!(Method::POST, request.method);
+        assert_eq!(url("participants/request"), request.url);
+    }
+
+    #[tokio::test]
+    async fn should_update_and_delete_participant() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NO_CONTENT, Value::Null);
+
+        let client = admin_client(&transport);
+        let participant = ParticipantContext::builder()
+            .id("consumer")
+            .identity("did:web:consumer-2")
+            .property("tier", "silver")
+            .build();
+
+        client.participants().update(&participant).await.unwrap();
+        client.participants().delete("consumer").await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(Method::PUT, requests[0].method);
+        assert_eq!(url("participants/consumer"), requests[0].url);
+
+        let body = requests[0].body.as_ref().unwrap();
+        assert_eq!("did:web:consumer-2", body["identity"]);
+        assert!(body.get("state").is_none());
+
+        assert_eq!(Method::DELETE, requests[1].method);
+        assert_eq!(url("participants/consumer"), requests[1].url);
+    }
+
+    #[tokio::test]
+    async fn should_activate_and_deactivate_participant() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NO_CONTENT, Value::Null);
+
+        let client = admin_client(&transport);
+        client.participants().activate("consumer").await.unwrap();
+        client.participants().deactivate("consumer").await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(
+            url("participants/consumer/state?isActive=true"),
+            requests[0].url
+        );
+        assert_eq!(
+            url("participants/consumer/state?isActive=false"),
+            requests[1].url
+        );
+        assert!(requests.iter().all(|request| request.body.is_none()));
+    }
+
+    #[tokio::test]
+    async fn should_get_participant_config() {
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!({
+                "@context": {},
+                "@type": "ParticipantContextConfig",
+                "entries": { "edc.participant.id": "consumer" },
+                "privateEntries": { "edc.iam.sts.oauth.client.secret": "secret" }
+            }),
+        );
+
+        let config = admin_client(&transport)
+            .participant_configs()
+            .get("consumer")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            &HashMap::from([("edc.participant.id".to_string(), "consumer".to_string())]),
+            config.entries()
+        );
+        assert_eq!(1, config.private_entries().len());
+        assert!(!format!("{:?}", config).contains("\"secret\""));
+        assert_eq!(
+            url("participants/consumer/config"),
+            transport.requests()[0].url
+        );
+    }
+}
@@ -60,7 +60,8 @@ mod create {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::CONFLICT,
-                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                ..
             }))
         ))
     }
@@ -116,7 +117,8 @@ mod delete {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::NOT_FOUND,
-                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                ..
             }))
         ))
     }
@@ -221,7 +223,8 @@ mod get {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::NOT_FOUND,
-                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                ..
             }))
         ))
     }
@@ -297,7 +300,8 @@ mod update {
             response,
             Err(Error::ManagementApi(ManagementApiError {
                 status_code: StatusCode::NOT_FOUND,
-                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                ..
             }))
         ))
     }
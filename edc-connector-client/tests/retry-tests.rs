@@ -0,0 +1,88 @@
+mod retry {
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use edc_connector_client::{
+        types::{asset::NewAsset, data_address::DataAddress},
+        EdcConnectorClient, Error, RetryPolicy, RetryReason,
+    };
+
+    #[allow(clippy::unwrap_used)]
+    fn unreachable_client(policy: RetryPolicy) -> EdcConnectorClient {
+        EdcConnectorClient::builder()
+            .management_url("http://127.0.0.1:1/management")
+            .retry_policy(policy)
+            .build()
+            .unwrap()
+    }
+
+    fn counting_policy(counter: Arc<AtomicU32>, retry_non_idempotent: bool) -> RetryPolicy {
+        RetryPolicy::builder()
+            .max_attempts(3)
+            .initial_backoff(Duration::from_millis(1))
+            .retry_non_idempotent(retry_non_idempotent)
+            .on_retry(move |event| {
+                assert!(matches!(event.reason, RetryReason::Transport(_)));
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .build()
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn new_asset() -> NewAsset {
+        NewAsset::builder()
+            .id("asset")
+            .data_address(DataAddress::builder().kind("type").build().unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn should_retry_idempotent_requests() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let client = unreachable_client(counting_policy(counter.clone(), false));
+
+        let response = client.assets().get("1").await;
+
+        assert!(matches!(response, Err(Error::Reqwest(_))));
+        assert_eq!(2, counter.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_non_idempotent_requests_by_default() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let client = unreachable_client(counting_policy(counter.clone(), false));
+
+        let response = client.assets().create(&new_asset()).await;
+
+        assert!(matches!(response, Err(Error::Reqwest(_))));
+        assert_eq!(0, counter.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn should_retry_non_idempotent_requests_when_enabled() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let client = unreachable_client(counting_policy(counter.clone(), true));
+
+        let response = client.assets().create(&new_asset()).await;
+
+        assert!(matches!(response, Err(Error::Reqwest(_))));
+        assert_eq!(2, counter.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_without_a_policy() {
+        let client = EdcConnectorClient::builder()
+            .management_url("http://127.0.0.1:1/management")
+            .build()
+            .unwrap();
+
+        let response = client.assets().get("1").await;
+
+        assert!(matches!(response, Err(Error::Reqwest(_))));
+    }
+}
@@ -51,7 +51,8 @@ mod secrets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::CONFLICT,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -98,7 +99,8 @@ mod secrets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -144,7 +146,8 @@ mod secrets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
@@ -197,9 +200,110 @@ mod secrets {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::NOT_FOUND,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
     }
+
+    mod query {
+        use edc_connector_client::{
+            types::{
+                query::{Pagination, Query},
+                secret::NewSecret,
+            },
+            EDC_NAMESPACE,
+        };
+        use futures::TryStreamExt;
+        use rstest::rstest;
+        use uuid::Uuid;
+
+        use crate::common::{provider_v3, provider_v4, setup_client, ClientParams};
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[tokio::test]
+        async fn should_query_secrets(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let id = Uuid::new_v4().to_string();
+            let secret = NewSecret::builder().id(&id).value("bar").build();
+
+            client.secrets().create(&secret).await.unwrap();
+
+            let query = Query::builder()
+                .filter(&format!("{}id", EDC_NAMESPACE), "=", id.as_str())
+                .build();
+
+            let secrets = client.secrets().query(query).await.unwrap();
+
+            assert_eq!(1, secrets.len());
+            assert_eq!("bar", secrets[0].value());
+        }
+
+        #[rstest]
+        #[case(provider_v3())]
+        #[case(provider_v4())]
+        #[tokio::test]
+        async fn should_stream_secret_ids(#[case] provider: ClientParams) {
+            let client = setup_client(provider);
+            let mut ids = vec![];
+            for _ in 0..3 {
+                let id = Uuid::new_v4().to_string();
+                let secret = NewSecret::builder().id(&id).value("bar").build();
+                client.secrets().create(&secret).await.unwrap();
+                ids.push(id);
+            }
+
+            let query = Query::builder()
+                .filter(&format!("{}id", EDC_NAMESPACE), "in", ids.clone())
+                .limit(1)
+                .build();
+
+            let mut streamed = client
+                .secrets()
+                .query_ids_stream_with(query, Pagination::builder().page_size(1).build())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+
+            streamed.sort();
+            ids.sort();
+            assert_eq!(ids, streamed);
+        }
+    }
+}
+
+mod secrets_fake {
+    use edc_connector_client::types::query::Query;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::common::fake::{FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[tokio::test]
+    async fn should_return_only_ids() {
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!([
+                { "@context": {}, "@id": "a", "@type": "Secret", "value": "top-secret" },
+                { "@context": {}, "@id": "b", "@type": "Secret", "value": "top-secret" }
+            ]),
+        );
+
+        let ids = transport
+            .client()
+            .secrets()
+            .query_ids(Query::default())
+            .await
+            .unwrap();
+
+        assert_eq!(vec!["a".to_string(), "b".to_string()], ids);
+        assert_eq!(
+            format!("{}/v3/secrets/request", FAKE_MANAGEMENT_URL),
+            transport.requests()[0].url
+        );
+    }
 }
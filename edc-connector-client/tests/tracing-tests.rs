@@ -0,0 +1,123 @@
+mod common;
+
+mod tracing {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use reqwest::StatusCode;
+    use serde_json::json;
+    use tracing::Level;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    use crate::common::fake::FakeTransport;
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        #[allow(clippy::unwrap_used)]
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Captured {
+        type Writer = Captured;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl Captured {
+        #[allow(clippy::unwrap_used)]
+        fn output(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn should_trace_management_api_calls_with_redacted_secrets() {
+        let captured = Captured::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Level::TRACE)
+            .with_writer(captured.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!({
+                "@context": {},
+                "@id": "secret-id",
+                "@type": "Secret",
+                "value": "super-secret-value"
+            }),
+        );
+
+        let secret = transport.client().secrets().get("secret-id").await.unwrap();
+
+        assert_eq!("super-secret-value", secret.value());
+
+        let output = captured.output();
+
+        assert!(output.contains("api=\"secrets\""));
+        assert!(output.contains("operation=\"get\""));
+        assert!(output.contains("edc.request"));
+        assert!(output.contains("http.method=GET"));
+        assert!(output.contains("/v3/secrets/secret-id"));
+        assert!(output.contains("status=200"));
+        assert!(output.contains("retries=0"));
+        assert!(output.contains("latency_ms="));
+        assert!(!output.contains("super-secret-value"));
+        assert!(!output.contains("123456"));
+    }
+
+    #[tokio::test]
+    async fn should_redact_expanded_edr_tokens() {
+        let captured = Captured::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Level::TRACE)
+            .with_writer(captured.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!({
+                "@context": {},
+                "@type": "https://w3id.org/edc/v0.0.1/ns/DataAddress",
+                "https://w3id.org/edc/v0.0.1/ns/type": "https://w3id.org/idsa/v4.1/HTTP",
+                "https://w3id.org/edc/v0.0.1/ns/endpoint": "http://provider/public",
+                "https://w3id.org/edc/v0.0.1/ns/authorization": "expanded-access-token",
+                "edc:refreshToken": "prefixed-refresh-token"
+            }),
+        );
+
+        let data_address = transport
+            .client()
+            .edrs()
+            .get_data_address("tp-1")
+            .await
+            .unwrap();
+
+        assert_eq!(Some("expanded-access-token"), data_address.authorization());
+
+        let output = captured.output();
+
+        assert!(output.contains("/v3/edrs/tp-1/dataaddress"));
+        assert!(!output.contains("expanded-access-token"));
+        assert!(!output.contains("prefixed-refresh-token"));
+    }
+}
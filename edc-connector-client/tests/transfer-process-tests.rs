@@ -78,7 +78,8 @@ mod transfer_processes {
                 response,
                 Err(Error::ManagementApi(ManagementApiError {
                     status_code: StatusCode::BAD_REQUEST,
-                    error_detail: ManagementApiErrorDetailKind::Parsed(..)
+                    error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                    ..
                 }))
             ))
         }
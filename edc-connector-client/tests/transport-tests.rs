@@ -0,0 +1,106 @@
+mod common;
+
+mod transport {
+    use std::time::Duration;
+
+    use edc_connector_client::{
+        types::{asset::NewAsset, data_address::DataAddress},
+        EdcConnectorClient, Error, ManagementApiError, ManagementApiErrorDetailKind, RetryPolicy,
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::common::fake::{asset, FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[tokio::test]
+    async fn should_send_requests_through_the_transport() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, asset("1"));
+
+        let asset = transport.client().assets().get("1").await.unwrap();
+
+        assert_eq!("1", asset.id());
+        assert_eq!(Ok(Some("bar".to_string())), asset.property::<String>("foo"));
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(Method::GET, requests[0].method);
+        assert_eq!(
+            format!("{}/v3/assets/1", FAKE_MANAGEMENT_URL),
+            requests[0].url
+        );
+        assert_eq!("123456", requests[0].headers["x-api-key"]);
+        assert!(requests[0].body.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_send_the_body_with_context() {
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::OK,
+            json!({ "@context": {}, "@id": "1", "createdAt": 1 }),
+        );
+
+        let asset = NewAsset::builder()
+            .id("1")
+            .data_address(DataAddress::builder().kind("HttpData").build().unwrap())
+            .build();
+
+        let response = transport.client().assets().create(&asset).await.unwrap();
+
+        assert_eq!("1", response.id());
+
+        let body = transport.requests()[0].body.clone().unwrap();
+        assert_eq!("1", body["@id"]);
+        assert_eq!(
+            "https://w3id.org/edc/v0.0.1/ns/",
+            body["@context"]["@vocab"]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_map_error_responses() {
+        let transport = FakeTransport::default();
+        transport.respond(
+            StatusCode::NOT_FOUND,
+            json!([{ "message": "not found", "type": "ObjectNotFound" }]),
+        );
+
+        let response = transport.client().assets().get("1").await;
+
+        assert!(matches!(
+            response,
+            Err(Error::ManagementApi(ManagementApiError {
+                status_code: StatusCode::NOT_FOUND,
+                error_detail: ManagementApiErrorDetailKind::Parsed(..),
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_retry_through_the_transport() {
+        let transport = FakeTransport::default();
+        transport
+            .fail("connection reset")
+            .respond(StatusCode::SERVICE_UNAVAILABLE, json!({}))
+            .respond(StatusCode::OK, asset("1"));
+
+        let client = EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .transport(transport.clone())
+            .retry_policy(
+                RetryPolicy::builder()
+                    .max_attempts(3)
+                    .initial_backoff(Duration::from_millis(1))
+                    .build(),
+            )
+            .build()
+            .unwrap();
+
+        let asset = client.assets().get("1").await.unwrap();
+
+        assert_eq!("1", asset.id());
+        assert_eq!(3, transport.requests().len());
+    }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+mod upsert {
+    use edc_connector_client::types::{
+        asset::Asset, data_address::DataAddress, response::UpsertOutcome, secret::Secret,
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::common::fake::{id_response, FakeTransport};
+
+    fn conflict() -> serde_json::Value {
+        json!([{ "message": "already exists", "type": "ObjectConflict" }])
+    }
+
+    #[tokio::test]
+    async fn should_create_when_missing() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, id_response("1"));
+
+        let asset = Asset::builder()
+            .id("1")
+            .data_address(DataAddress::builder().kind("type").build().unwrap())
+            .build();
+
+        let outcome = transport.client().assets().upsert(&asset).await.unwrap();
+
+        assert_eq!(UpsertOutcome::Created, outcome);
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(Method::POST, requests[0].method);
+    }
+
+    #[tokio::test]
+    async fn should_update_on_conflict() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::CONFLICT, conflict())
+            .respond(StatusCode::NO_CONTENT, json!(null));
+
+        let secret = Secret::builder().id("1").value("value").build();
+
+        let outcome = transport.client().secrets().upsert(&secret).await.unwrap();
+
+        assert_eq!(UpsertOutcome::Updated, outcome);
+
+        let requests = transport.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!(Method::POST, requests[0].method);
+        assert_eq!(Method::PUT, requests[1].method);
+        assert_eq!("1", requests[1].body.as_ref().unwrap()["@id"]);
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_other_errors() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::BAD_REQUEST, json!([]));
+
+        let secret = Secret::builder().id("1").value("value").build();
+
+        let err = transport
+            .client()
+            .secrets()
+            .upsert(&secret)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_bad_request());
+        assert_eq!(1, transport.requests().len());
+    }
+}
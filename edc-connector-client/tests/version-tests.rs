@@ -0,0 +1,160 @@
+mod common;
+
+mod detect_version {
+    use edc_connector_client::{
+        Auth, EdcConnectorApiVersion, EdcConnectorClient, Error, ErrorKind,
+    };
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::common::fake::{FakeTransport, FAKE_MANAGEMENT_URL};
+
+    #[tokio::test]
+    async fn should_prefer_v4() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, json!([]));
+
+        let version = transport.client().detect_version().await.unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V4, version);
+        assert_eq!(
+            format!("{}/v4beta/assets/request", FAKE_MANAGEMENT_URL),
+            transport.requests()[0].url
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_v3() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NOT_FOUND, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let version = transport.client().detect_version().await.unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V3, version);
+        assert_eq!(
+            format!("{}/v3/assets/request", FAKE_MANAGEMENT_URL),
+            transport.requests()[1].url
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_the_versions_tried() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NOT_FOUND, json!([]))
+            .respond(StatusCode::NOT_FOUND, json!([]));
+
+        let err = transport.client().detect_version().await.unwrap_err();
+
+        match &err {
+            Error::VersionDetection(detection) => {
+                assert_eq!(FAKE_MANAGEMENT_URL, detection.management_url);
+                assert_eq!(2, detection.attempts.len());
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+
+        let message = err.to_string();
+        assert!(message.contains("v4beta"));
+        assert!(message.contains("v3"));
+    }
+
+    #[tokio::test]
+    async fn should_not_mask_auth_errors() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::UNAUTHORIZED, json!([]));
+
+        let err = transport.client().detect_version().await.unwrap_err();
+
+        assert_eq!(ErrorKind::Unauthorized, err.kind());
+        assert_eq!(1, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_not_mask_validation_errors() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::BAD_REQUEST, json!([]));
+
+        let err = transport.client().detect_version().await.unwrap_err();
+
+        assert_eq!(ErrorKind::BadRequest, err.kind());
+        assert_eq!(1, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_skip_detection_for_participant_contexts() {
+        let transport = FakeTransport::default();
+
+        let version = transport
+            .client()
+            .for_participant("provider")
+            .detect_version()
+            .await
+            .unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V4, version);
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_build_participant_clients_with_v4_contexts() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, json!([]));
+
+        let client = EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .with_auth(Auth::api_token("123456"))
+            .participant_context("provider")
+            .transport(transport.clone())
+            .build_detected()
+            .await
+            .unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V4, client.api_version());
+
+        client.assets().query(Default::default()).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(
+            format!(
+                "{}/v4alpha/participants/provider/assets/request",
+                FAKE_MANAGEMENT_URL
+            ),
+            requests[0].url
+        );
+        let context = &requests[0].body.as_ref().unwrap()["@context"];
+        assert_eq!(
+            &json!(["https://w3id.org/edc/connector/management/v2"]),
+            context
+        );
+    }
+
+    #[tokio::test]
+    async fn should_build_client_with_detected_version() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NOT_FOUND, json!([]))
+            .respond(StatusCode::OK, json!([]))
+            .respond(StatusCode::OK, json!([]));
+
+        let client = EdcConnectorClient::builder()
+            .management_url(FAKE_MANAGEMENT_URL)
+            .with_auth(Auth::api_token("123456"))
+            .version(EdcConnectorApiVersion::V4)
+            .transport(transport.clone())
+            .build_detected()
+            .await
+            .unwrap();
+
+        assert_eq!(EdcConnectorApiVersion::V3, client.api_version());
+
+        client.assets().query(Default::default()).await.unwrap();
+        assert_eq!(
+            format!("{}/v3/assets/request", FAKE_MANAGEMENT_URL),
+            transport.requests()[2].url
+        );
+    }
+}
@@ -0,0 +1,146 @@
+mod common;
+
+mod wait {
+    use std::time::Duration;
+
+    use edc_connector_client::{
+        types::{
+            contract_negotiation::ContractNegotiationState, transfer_process::TransferProcessState,
+        },
+        Error, WaitError, WaitOptions,
+    };
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::common::fake::{negotiation, transfer, FakeTransport};
+
+    fn fast() -> WaitOptions {
+        WaitOptions::builder()
+            .timeout(Duration::from_secs(5))
+            .poll_interval(Duration::from_millis(1))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn should_wait_for_the_target_negotiation_state() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, negotiation("REQUESTED"))
+            .respond(StatusCode::OK, negotiation("AGREED"))
+            .respond(StatusCode::OK, negotiation("FINALIZED"));
+
+        let negotiation = transport
+            .client()
+            .contract_negotiations()
+            .wait_for_state("cn-1", ContractNegotiationState::Finalized, fast())
+            .await
+            .unwrap();
+
+        assert_eq!(&ContractNegotiationState::Finalized, negotiation.state());
+        assert_eq!(
+            Some(&"agreement-1".to_string()),
+            negotiation.contract_agreement_id()
+        );
+        assert_eq!(3, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_fail_fast_on_terminal_state() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, negotiation("REQUESTED"))
+            .respond(StatusCode::OK, negotiation("TERMINATED"));
+
+        let result = transport
+            .client()
+            .contract_negotiations()
+            .wait_for_state("cn-1", ContractNegotiationState::Finalized, fast())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Wait(WaitError::TerminalState { ref state, .. })) if state == "TERMINATED"
+        ));
+        assert_eq!(2, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_wait_through_completed_for_deprovisioned() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, transfer("STARTED"))
+            .respond(StatusCode::OK, transfer("COMPLETED"))
+            .respond(StatusCode::OK, transfer("DEPROVISIONING"))
+            .respond(StatusCode::OK, transfer("DEPROVISIONED"));
+
+        let transfer = transport
+            .client()
+            .transfer_processes()
+            .wait_for_state("tp-1", TransferProcessState::Deprovisioned, fast())
+            .await
+            .unwrap();
+
+        assert_eq!(&TransferProcessState::Deprovisioned, transfer.state());
+        assert_eq!(4, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_fail_fast_when_the_target_is_unreachable() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, transfer("REQUESTED"))
+            .respond(StatusCode::OK, transfer("COMPLETED"));
+
+        let result = transport
+            .client()
+            .transfer_processes()
+            .wait_for_state("tp-1", TransferProcessState::Started, fast())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Wait(WaitError::TerminalState { ref state, .. })) if state == "COMPLETED"
+        ));
+        assert_eq!(2, transport.requests().len());
+    }
+
+    #[tokio::test]
+    async fn should_time_out_with_the_last_state() {
+        let transport = FakeTransport::default();
+        for _ in 0..100 {
+            transport.respond(StatusCode::OK, transfer("REQUESTED"));
+        }
+
+        let options = WaitOptions::builder()
+            .timeout(Duration::from_millis(50))
+            .poll_interval(Duration::from_millis(5))
+            .backoff(2.0)
+            .build();
+
+        let result = transport
+            .client()
+            .transfer_processes()
+            .wait_for_state("tp-1", TransferProcessState::Started, options)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Wait(WaitError::Timeout { ref last_state, .. }))
+                if last_state.as_deref() == Some("REQUESTED")
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_propagate_errors_while_polling() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::NOT_FOUND, json!([]));
+
+        let result = transport
+            .client()
+            .transfer_processes()
+            .wait_for_state("tp-1", TransferProcessState::Started, fast())
+            .await;
+
+        assert!(result.unwrap_err().is_not_found());
+    }
+}
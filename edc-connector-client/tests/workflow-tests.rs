@@ -0,0 +1,346 @@
+mod common;
+
+mod consume {
+    use std::time::Duration;
+
+    use edc_connector_client::{
+        types::policy::PolicyKind,
+        workflow::{ConsumeRequest, WorkflowError, WorkflowStep},
+        Error, ErrorKind, WaitOptions,
+    };
+    use reqwest::StatusCode;
+    use serde_json::{json, Value};
+
+    use crate::common::fake::{id_response, negotiation, transfer, FakeTransport};
+
+    fn dataset() -> Value {
+        json!({
+            "@context": {},
+            "@id": "asset-1",
+            "hasPolicy": [
+                { "@id": "offer-1", "@type": "Offer", "permission": [] },
+                { "@id": "offer-2", "@type": "Offer", "permission": [{ "action": "use" }] }
+            ]
+        })
+    }
+
+    fn agreement() -> Value {
+        json!({
+            "@context": {},
+            "@id": "agreement-1",
+            "contractSigningDate": 1,
+            "assetId": "asset-1",
+            "consumerId": "consumer",
+            "providerId": "provider",
+            "policy": { "@type": "Agreement", "permission": [{ "action": "use" }] }
+        })
+    }
+
+    fn data_address() -> Value {
+        json!({
+            "@context": {},
+            "@type": "DataAddress",
+            "endpoint": "http://provider/public",
+            "authorization": "token"
+        })
+    }
+
+    fn request() -> ConsumeRequest {
+        let wait = WaitOptions::builder()
+            .poll_interval(Duration::from_millis(1))
+            .build();
+
+        ConsumeRequest::builder()
+            .asset_id("asset-1")
+            .counter_party_address("http://provider/protocol")
+            .counter_party_id("provider")
+            .offer_selector(|offer| !offer.permissions().is_empty())
+            .negotiation_wait(wait.clone())
+            .transfer_wait(wait)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn should_consume_an_asset() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, dataset())
+            .respond(StatusCode::OK, id_response("cn-1"))
+            .respond(StatusCode::OK, negotiation("FINALIZED"))
+            .respond(StatusCode::OK, agreement())
+            .respond(StatusCode::OK, id_response("tp-1"))
+            .respond(StatusCode::OK, transfer("STARTED"))
+            .respond(StatusCode::OK, data_address());
+
+        let outcome = transport
+            .client()
+            .consumer()
+            .consume(&request())
+            .await
+            .unwrap();
+
+        assert_eq!("agreement-1", outcome.agreement().id());
+        assert_eq!("tp-1", outcome.transfer_process().id());
+        assert_eq!(
+            Some("http://provider/public".to_string()),
+            outcome
+                .data_address()
+                .property::<String>("endpoint")
+                .unwrap()
+        );
+
+        let requests = transport.requests();
+        assert_eq!(7, requests.len());
+
+        let contract_request = requests[1].body.as_ref().unwrap();
+        assert_eq!("offer-2", contract_request["policy"]["@id"]);
+        assert_eq!("provider", contract_request["policy"]["assigner"]);
+        assert_eq!("asset-1", contract_request["policy"]["target"]);
+        assert_eq!(
+            json!(PolicyKind::Offer),
+            contract_request["policy"]["@type"]
+        );
+
+        let transfer_request = requests[4].body.as_ref().unwrap();
+        assert_eq!("agreement-1", transfer_request["contractId"]);
+        assert_eq!("HttpData-PULL", transfer_request["transferType"]);
+    }
+
+    #[tokio::test]
+    async fn should_report_no_matching_offer() {
+        let transport = FakeTransport::default();
+        transport.respond(StatusCode::OK, dataset());
+
+        let request = ConsumeRequest::builder()
+            .asset_id("asset-1")
+            .counter_party_address("http://provider/protocol")
+            .counter_party_id("provider")
+            .offer_selector(|offer| offer.id().is_some_and(|id| id == "offer-3"))
+            .build();
+
+        let result = transport.client().consumer().consume(&request).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Workflow(ref err @ WorkflowError::NoMatchingOffer { .. }))
+                if err.step() == WorkflowStep::SelectOffer
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_report_the_failing_step() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, dataset())
+            .respond(StatusCode::OK, id_response("cn-1"))
+            .respond(StatusCode::OK, negotiation("FINALIZED"))
+            .respond(StatusCode::OK, agreement())
+            .respond(StatusCode::BAD_REQUEST, json!([]));
+
+        let err = transport
+            .client()
+            .consumer()
+            .consume(&request())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            &err,
+            Error::Workflow(err) if err.step() == WorkflowStep::InitiateTransfer
+        ));
+        assert_eq!(ErrorKind::BadRequest, err.kind());
+        assert!(err.endpoint().is_some());
+    }
+}
+
+mod publish {
+    use edc_connector_client::{
+        types::{
+            asset::NewAsset,
+            data_address::DataAddress,
+            policy::{Action, Permission, Policy},
+        },
+        workflow::{PublishRequest, PublishedOffering, WorkflowStep},
+        BuilderError, Error,
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::{json, Value};
+
+    use crate::common::fake::{id_response, FakeTransport, FAKE_MANAGEMENT_URL};
+
+    fn use_policy() -> Policy {
+        Policy::builder()
+            .permission(Permission::builder().action(Action::simple("use")).build())
+            .build()
+    }
+
+    fn request() -> PublishRequest {
+        request_with(None, use_policy())
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn request_with(policy_id: Option<&str>, contract_policy: Policy) -> PublishRequest {
+        PublishRequest::builder()
+            .asset(
+                NewAsset::builder()
+                    .id("asset-1")
+                    .data_address(
+                        DataAddress::builder()
+                            .kind("HttpData")
+                            .property("baseUrl", "https://jsonplaceholder.typicode.com/users")
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .access_policy(use_policy())
+            .contract_policy(contract_policy)
+            .maybe_access_policy_id(policy_id)
+            .maybe_contract_policy_id(policy_id)
+            .contract_definition_id("cd-1")
+            .build()
+    }
+
+    fn calls(transport: &FakeTransport) -> Vec<(Method, String)> {
+        transport
+            .requests()
+            .into_iter()
+            .map(|request| (request.method, request.url.replace(FAKE_MANAGEMENT_URL, "")))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_publish_an_offering() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, id_response("asset-1"))
+            .respond(StatusCode::OK, id_response("access-1"))
+            .respond(StatusCode::OK, id_response("contract-1"))
+            .respond(StatusCode::OK, id_response("cd-1"));
+
+        let offering = transport
+            .client()
+            .provider()
+            .publish(&request())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            PublishedOffering::new("asset-1", "access-1", "contract-1", "cd-1"),
+            offering
+        );
+
+        let requests = transport.requests();
+        let definition = requests[3].body.as_ref().unwrap();
+        assert_eq!("cd-1", definition["@id"]);
+        assert_eq!("access-1", definition["accessPolicyId"]);
+        assert_eq!("contract-1", definition["contractPolicyId"]);
+        assert_eq!("asset-1", definition["assetsSelector"][0]["operandRight"]);
+    }
+
+    #[tokio::test]
+    async fn should_roll_back_when_a_step_fails() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, id_response("asset-1"))
+            .respond(StatusCode::OK, id_response("access-1"))
+            .respond(StatusCode::OK, id_response("contract-1"))
+            .respond(StatusCode::BAD_REQUEST, json!([]))
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NO_CONTENT, Value::Null);
+
+        let result = transport.client().provider().publish(&request()).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Workflow(ref err)) if err.step() == WorkflowStep::CreateContractDefinition
+        ));
+
+        assert_eq!(
+            vec![
+                (
+                    Method::DELETE,
+                    "/v3/policydefinitions/contract-1".to_string()
+                ),
+                (Method::DELETE, "/v3/policydefinitions/access-1".to_string()),
+                (Method::DELETE, "/v3/assets/asset-1".to_string()),
+            ],
+            calls(&transport)[4..]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_create_a_shared_policy_once() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::OK, id_response("asset-1"))
+            .respond(StatusCode::OK, id_response("policy-1"))
+            .respond(StatusCode::OK, id_response("cd-1"));
+
+        let offering = transport
+            .client()
+            .provider()
+            .publish(&request_with(Some("policy-1"), use_policy()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            PublishedOffering::new("asset-1", "policy-1", "policy-1", "cd-1"),
+            offering
+        );
+
+        let requests = transport.requests();
+        assert_eq!(3, requests.len());
+        let definition = requests[2].body.as_ref().unwrap();
+        assert_eq!("policy-1", definition["accessPolicyId"]);
+        assert_eq!("policy-1", definition["contractPolicyId"]);
+    }
+
+    #[tokio::test]
+    async fn should_reject_different_policies_under_a_shared_id() {
+        let transport = FakeTransport::default();
+        let request = request_with(Some("policy-1"), Policy::builder().build());
+
+        let result = transport.client().provider().publish(&request).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Builder(BuilderError::InvalidProperty(ref property, _)))
+                if property == "contract_policy"
+        ));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_unpublish_in_dependency_order() {
+        let transport = FakeTransport::default();
+        transport
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NOT_FOUND, json!([]))
+            .respond(StatusCode::NO_CONTENT, Value::Null)
+            .respond(StatusCode::NO_CONTENT, Value::Null);
+
+        let offering = PublishedOffering::new("asset-1", "access-1", "contract-1", "cd-1");
+
+        transport
+            .client()
+            .provider()
+            .unpublish(&offering)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (Method::DELETE, "/v3/contractdefinitions/cd-1".to_string()),
+                (
+                    Method::DELETE,
+                    "/v3/policydefinitions/contract-1".to_string()
+                ),
+                (Method::DELETE, "/v3/policydefinitions/access-1".to_string()),
+                (Method::DELETE, "/v3/assets/asset-1".to_string()),
+            ],
+            calls(&transport)
+        );
+    }
+}